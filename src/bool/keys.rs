@@ -1,16 +1,202 @@
-use std::{collections::HashMap, hash::Hash, marker::PhantomData};
+use std::{collections::HashMap, marker::PhantomData};
+
+use itertools::izip;
+use num_traits::{FromPrimitive, PrimInt, ToPrimitive};
+use sha2::{Digest, Sha256};
 
 use crate::{
     backend::{ModInit, VectorOps},
     lwe::LweSecret,
     pbs::WithShoupRepr,
-    random::{NewWithSeed, RandomFillUniformInModulus},
+    random::{DefaultSecureRng, NewWithSeed, RandomFillUniformInModulus, RandomUniformDist},
     rgsw::RlweSecret,
-    utils::{ToShoup, WithLocal},
-    Decryptor, Encryptor, Matrix, MatrixEntity, MatrixMut, MultiPartyDecryptor, RowEntity, RowMut,
+    utils::{fill_random_ternary_secret_with_hamming_weight, ToShoup, WithLocal},
+    Decryptor, Encryptor, Matrix, MatrixEntity, MatrixMut, MultiPartyDecryptor, Ntt, RowEntity,
+    RowMut, Secret,
+};
+
+use super::{
+    parameters, BoolEvaluator, BoolParameters, CiphertextModulus, NonInteractiveMultiPartyCrs,
 };
 
-use super::{parameters, BoolEvaluator, BoolParameters, CiphertextModulus};
+// Little-endian, fixed-width (8 byte) element encoding for the `to_bytes`/
+// `from_bytes` impls of the seeded key/share types below. Mirrors the
+// encoding `rgsw`'s `Seeded*` types use for the same purpose.
+fn write_element<E: ToPrimitive>(out: &mut Vec<u8>, el: E) {
+    out.extend_from_slice(&el.to_u64().unwrap().to_le_bytes());
+}
+
+fn read_element<E: FromPrimitive>(bytes: &[u8], at: &mut usize) -> E {
+    let v = u64::from_le_bytes(bytes[*at..*at + 8].try_into().unwrap());
+    *at += 8;
+    E::from_u64(v).unwrap()
+}
+
+fn write_len(out: &mut Vec<u8>, len: usize) {
+    write_element(out, len as u64);
+}
+
+fn read_len(bytes: &[u8], at: &mut usize) -> usize {
+    read_element::<u64>(bytes, at) as usize
+}
+
+fn read_seed(bytes: &[u8], at: &mut usize) -> [u8; 32] {
+    let seed: [u8; 32] = bytes[*at..*at + 32].try_into().unwrap();
+    *at += 32;
+    seed
+}
+
+fn write_row<E: ToPrimitive + Copy>(out: &mut Vec<u8>, row: &[E]) {
+    row.iter().for_each(|el| write_element(out, *el));
+}
+
+fn read_row<E: FromPrimitive>(bytes: &[u8], at: &mut usize, row: &mut [E]) {
+    row.iter_mut().for_each(|el| *el = read_element(bytes, at));
+}
+
+fn write_matrix<M: Matrix>(out: &mut Vec<u8>, mat: &M)
+where
+    M::MatElement: ToPrimitive + Copy,
+{
+    let (rows, cols) = mat.dimension();
+    write_len(out, rows);
+    write_len(out, cols);
+    mat.iter_rows().for_each(|r| write_row(out, r.as_ref()));
+}
+
+fn read_matrix<M: MatrixMut + MatrixEntity>(bytes: &[u8], at: &mut usize) -> M
+where
+    M::R: RowMut,
+    M::MatElement: FromPrimitive,
+{
+    let rows = read_len(bytes, at);
+    let cols = read_len(bytes, at);
+    let mut mat = M::zeros(rows, cols);
+    mat.iter_rows_mut()
+        .for_each(|r| read_row(bytes, at, r.as_mut()));
+    mat
+}
+
+fn write_matrix_vec<M: Matrix>(out: &mut Vec<u8>, mats: &[M])
+where
+    M::MatElement: ToPrimitive + Copy,
+{
+    write_len(out, mats.len());
+    mats.iter().for_each(|m| write_matrix(out, m));
+}
+
+fn read_matrix_vec<M: MatrixMut + MatrixEntity>(bytes: &[u8], at: &mut usize) -> Vec<M>
+where
+    M::R: RowMut,
+    M::MatElement: FromPrimitive,
+{
+    let len = read_len(bytes, at);
+    (0..len).map(|_| read_matrix(bytes, at)).collect()
+}
+
+fn write_auto_keys<M: Matrix>(out: &mut Vec<u8>, auto_keys: &HashMap<usize, M>)
+where
+    M::MatElement: ToPrimitive + Copy,
+{
+    write_len(out, auto_keys.len());
+    auto_keys.iter().for_each(|(k, m)| {
+        write_len(out, *k);
+        write_matrix(out, m);
+    });
+}
+
+fn read_auto_keys<M: MatrixMut + MatrixEntity>(bytes: &[u8], at: &mut usize) -> HashMap<usize, M>
+where
+    M::R: RowMut,
+    M::MatElement: FromPrimitive,
+{
+    let len = read_len(bytes, at);
+    (0..len)
+        .map(|_| {
+            let k = read_len(bytes, at);
+            let m = read_matrix(bytes, at);
+            (k, m)
+        })
+        .collect()
+}
+
+/// Commitment to a party's round-1 DKG broadcast (see
+/// [`aggregate_collective_pk_shares_with_complaints`] /
+/// [`aggregate_server_key_shares_with_complaints`]): a party hashes its share
+/// and publishes the `ShareCommitment` before revealing the share itself, so
+/// the aggregator can catch a party that reveals something other than what
+/// it committed to.
+///
+/// Backed by SHA-256, so binding only relies on second-preimage/collision
+/// resistance of a real cryptographic hash, not on `DefaultHasher`'s
+/// fixed, unkeyed, non-cryptographic state.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ShareCommitment([u8; 32]);
+
+fn hash_bytes(bytes: &[u8]) -> ShareCommitment {
+    ShareCommitment(Sha256::digest(bytes).into())
+}
+
+/// Runs the complaint round of a synchronous commit-then-reveal DKG:
+/// `commitments[i]` must be the commitment party `i` broadcast before
+/// revealing `revealed[i]`. Returns the indices (into `revealed`) of the
+/// shares that match their commitment and agree with the *majority* of
+/// correctly-committed shares on `cr_seed`/`parameters`, followed by the
+/// indices that don't -- the latter are reported back to the group as
+/// complaints against those parties.
+///
+/// The reference point a share is compared against is chosen by majority
+/// vote among the correctly-committed shares, not fixed to `revealed[0]`:
+/// comparing everyone against an arbitrary element lets a single malicious
+/// party at that index submit garbage `cr_seed`/`parameters` and have the
+/// function qualify only parties who (by chance) happen to match its
+/// garbage, excluding every honest party -- exactly the Byzantine failure
+/// a complaint round exists to prevent.
+fn qualify_shares<T>(
+    commitments: &[ShareCommitment],
+    revealed: &[T],
+    commit: impl Fn(&T) -> ShareCommitment,
+    same_cr_seed: impl Fn(&T, &T) -> bool,
+    same_parameters: impl Fn(&T, &T) -> bool,
+) -> (Vec<usize>, Vec<usize>) {
+    assert!(!revealed.is_empty());
+    assert_eq!(commitments.len(), revealed.len());
+
+    let agrees = |i: usize, j: usize| same_cr_seed(&revealed[i], &revealed[j]) && same_parameters(&revealed[i], &revealed[j]);
+
+    // A share that doesn't even match its own commitment can't be part of
+    // the honest majority.
+    let commit_ok: Vec<usize> = izip!(commitments.iter(), revealed.iter(), 0..)
+        .filter(|(commitment, share, _)| **commitment == commit(share))
+        .map(|(_, _, index)| index)
+        .collect();
+
+    // The majority reference: the commit-ok share that the most other
+    // commit-ok shares agree with (ties broken in favor of the
+    // lowest index, for determinism).
+    let mut reference = None;
+    let mut best_count = 0usize;
+    commit_ok.iter().for_each(|&i| {
+        let count = commit_ok.iter().filter(|&&j| agrees(i, j)).count();
+        if count > best_count {
+            best_count = count;
+            reference = Some(i);
+        }
+    });
+
+    let mut qualified = Vec::new();
+    let mut excluded = Vec::new();
+    (0..revealed.len()).for_each(|index| {
+        let honest = commit_ok.contains(&index) && reference.is_some_and(|r| agrees(index, r));
+        if honest {
+            qualified.push(index);
+        } else {
+            excluded.push(index);
+        }
+    });
+
+    (qualified, excluded)
+}
 
 trait SinglePartyClientKey {
     type Element;
@@ -120,6 +306,296 @@ mod impl_ck {
     }
 }
 
+/// A single party's evaluation-point share of a Shamir-shared ideal RLWE
+/// secret (see [`shamir_share_rlwe_secret`]). Unlike `RlweSecret`, whose
+/// coefficients are always ternary, a share's coefficients range over the
+/// whole ring -- summing in the higher-degree terms of the sharing
+/// polynomial doesn't keep the ternary shape, even though the constant
+/// term (the ideal secret itself) does.
+#[derive(Clone)]
+pub struct ThresholdRlweSecretShare {
+    /// This party's evaluation point (`1..=parties`; `0` is reserved for
+    /// the ideal secret).
+    index: usize,
+    values: Vec<i64>,
+}
+
+impl ThresholdRlweSecretShare {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+}
+
+/// Shamir-shares `s` across `parties` evaluation points so that any
+/// `threshold + 1` of them can jointly reconstruct it (and so jointly
+/// decrypt): samples a degree-`threshold` polynomial
+/// `f(X) = s + a_1*X + ... + a_threshold*X^threshold` whose non-constant
+/// coefficients are drawn with the same ternary, fixed-Hamming-weight
+/// distribution as an ordinary `RlweSecret`, and hands party `i` the
+/// evaluation `f(i)`.
+pub fn shamir_share_rlwe_secret(
+    s: &RlweSecret,
+    hamming_weight: usize,
+    threshold: usize,
+    parties: usize,
+) -> Vec<ThresholdRlweSecretShare> {
+    assert!(
+        parties > threshold,
+        "need more parties than the threshold for a {threshold}-of-{parties} scheme"
+    );
+    let ring_size = s.values().len();
+
+    let coefficients: Vec<Vec<i32>> = (0..threshold)
+        .map(|_| {
+            DefaultSecureRng::with_local_mut(|rng| {
+                let mut out = vec![0i32; ring_size];
+                fill_random_ternary_secret_with_hamming_weight(&mut out, hamming_weight, rng);
+                out
+            })
+        })
+        .collect();
+
+    (1..=parties)
+        .map(|i| {
+            let x = i as i64;
+            let mut values: Vec<i64> = s.values().iter().map(|&s_j| s_j as i64).collect();
+            coefficients.iter().enumerate().for_each(|(k, a_k)| {
+                let x_pow = x.pow(k as u32 + 1);
+                izip!(values.iter_mut(), a_k.iter())
+                    .for_each(|(v, a_kj)| *v += (*a_kj as i64) * x_pow);
+            });
+            ThresholdRlweSecretShare { index: i, values }
+        })
+        .collect()
+}
+
+fn factorial(n: u32) -> i64 {
+    (1..=n as i64).product::<i64>().max(1)
+}
+
+/// Integer-scaled Lagrange coefficient `Δ*λ_i` for reconstructing `f(0)`
+/// from the active set `indices`, a subset of the `total_parties` points
+/// `1..=total_parties` the sharing polynomial was evaluated at.
+///
+/// `Δ = total_parties!` rather than `indices.len()!`: for any subset of
+/// distinct integers drawn from `1..=total_parties`, every pairwise-product
+/// Lagrange denominator divides `total_parties!` (the standard trick behind
+/// Shoup's practical threshold schemes), but it need not divide the
+/// smaller `indices.len()!` -- e.g. for `indices = {1, 3, 5}` the
+/// denominator at `i = 1` is `(1-3)*(1-5) = 8`, which `3! = 6` doesn't
+/// divide but `5! = 120` does.
+fn scaled_lagrange_coefficient_at_zero(indices: &[usize], i: usize, total_parties: usize) -> i64 {
+    let delta = factorial(total_parties as u32);
+    let mut num = delta;
+    let mut den = 1i64;
+    indices.iter().filter(|&&j| j != i).for_each(|&j| {
+        num *= -(j as i64);
+        den *= i as i64 - j as i64;
+    });
+    debug_assert_eq!(
+        num % den,
+        0,
+        "Δ = total_parties! must clear every pairwise denominator"
+    );
+    num / den
+}
+
+/// Combines `threshold + 1` (or more) parties' partial decryption terms
+/// `(party_index, <a, share_i> + smudging noise)` -- computed per
+/// ciphertext by the evaluator from a [`ThresholdRlweSecretShare`], the
+/// same shape as the existing additive `DecryptionShare` -- into the
+/// reconstructed phase, via the Lagrange interpolation of `f` at `0`.
+///
+/// `total_parties` must be the same `n` the shares were generated for (the
+/// `parties` argument to [`shamir_share_rlwe_secret`]), not merely the size
+/// of the active subset in `shares` -- see
+/// [`scaled_lagrange_coefficient_at_zero`] for why the smaller value is
+/// unsound. Every coefficient is scaled by `Δ = total_parties!` to clear
+/// the non-invertible denominators a prime-field Lagrange coefficient would
+/// hit over a power-of-two `CiphertextModulus`; the division by `Δ` is
+/// folded into this function's own rounding rather than inverted mod `q`,
+/// which only works while `Δ` is a plain integer divisor, i.e. while
+/// callers keep `total_parties` small enough that `total_parties!` stays
+/// below `q`. (The alternative -- picking a `Δ` coprime to `q` and
+/// inverting it mod `q` -- needs an odd `Δ`, which `n!` for `n >= 2` never
+/// is, so it isn't an option here.)
+pub fn aggregate_threshold_decryption_shares(
+    shares: &[(usize, i64)],
+    total_parties: usize,
+    q: u64,
+) -> i64 {
+    assert!(!shares.is_empty(), "need at least one partial share");
+    let indices: Vec<usize> = shares.iter().map(|(i, _)| *i).collect();
+    let delta = factorial(total_parties as u32);
+    assert!(
+        (delta as u128) < q as u128,
+        "Δ = {delta} (total_parties!) must stay below the ciphertext modulus {q}; lower total_parties"
+    );
+
+    let scaled_sum: i64 = shares
+        .iter()
+        .map(|(i, partial)| {
+            scaled_lagrange_coefficient_at_zero(&indices, *i, total_parties) * partial
+        })
+        .sum();
+    scaled_sum.div_euclid(delta)
+}
+
+/// The per-ciphertext step [`shamir_share_rlwe_secret`]/
+/// [`aggregate_threshold_decryption_shares`] were missing: turns an RLWE
+/// ciphertext's mask row `ct_a` plus this party's [`ThresholdRlweSecretShare`]
+/// into that party's partial decryption term for every coefficient --
+/// `-(s_i(X) * ct_a(X)) mod x^n + 1`, the share-level analogue of the `-s*a`
+/// term `decrypt_rlwe` computes from the full secret, with fresh smudging
+/// noise added so a single share (or any strict subset below `threshold +
+/// 1`) leaks only a negligible amount about `s_i`.
+///
+/// Computed as an exact (unreduced) integer negacyclic convolution rather
+/// than via a `ModOp`/`NttOp` ring multiplication: [`shamir_share_rlwe_secret`]
+/// shares `s` itself the same way, as plain integer polynomial evaluations,
+/// and [`aggregate_threshold_decryption_shares`]'s Δ-scaled Lagrange
+/// reconstruction only recovers the right answer when every party's partial
+/// is an exact evaluation of that same integer-coefficient polynomial --
+/// reducing a partial mod `q` before combining introduces a multiple of `q`
+/// that the Δ division can no longer cancel out. Reduce the aggregated
+/// result (plus `ct_b[j]`) mod `q` only once, at the very end; callers must
+/// keep `ring_size`, `q` and the share magnitudes small enough that the
+/// convolution doesn't overflow `i64`, the same scale discipline
+/// `aggregate_threshold_decryption_shares` already asks for via its
+/// `Δ = total_parties! < q` assertion.
+///
+/// Feed the result for `threshold + 1` (or more) parties, one coefficient at
+/// a time -- `(share.index(), shares[i][j])` -- into
+/// [`aggregate_threshold_decryption_shares`], then add `ct_b[j]` and reduce
+/// mod `q` to recover `m[j] + e[j]`.
+pub fn gen_threshold_decryption_share(
+    ct_a: &[u64],
+    share: &ThresholdRlweSecretShare,
+    smudging_bound: u64,
+) -> Vec<i64> {
+    let ring_size = ct_a.len();
+    assert_eq!(
+        share.values().len(),
+        ring_size,
+        "share and ciphertext mask must have the same ring size"
+    );
+
+    let smudging: Vec<u64> = sample_masking_row(ring_size, smudging_bound);
+
+    // Negacyclic convolution `-(share * ct_a) mod x^n + 1`: `x^n = -1` flips
+    // the sign of every wrapped-around term, mirroring `decrypt_rlwe`'s
+    // `-s*a` sign convention.
+    (0..ring_size)
+        .map(|k| {
+            let conv: i64 = (0..ring_size)
+                .map(|j| {
+                    let a_kj = ct_a[(k + ring_size - j) % ring_size] as i64;
+                    if j <= k {
+                        share.values()[j] * a_kj
+                    } else {
+                        -(share.values()[j] * a_kj)
+                    }
+                })
+                .sum();
+            -conv + smudging[k] as i64
+        })
+        .collect()
+}
+
+/// One committee member's contribution to a proactive zero-sharing round,
+/// used to re-randomize (or hand off) a multi-party secret without
+/// changing the ideal secret it reconstructs to. Derived purely from
+/// pairwise seeds shared with every other member, so no interaction beyond
+/// the (out-of-band) seed agreement is needed: every ordered pair `(i, j)`
+/// contributes `+r` to one party's total and `-r` to the other's, so
+/// summing every member's contribution together always yields the zero
+/// polynomial, regardless of what `r` actually is.
+pub struct ZeroShareContribution {
+    values: Vec<i64>,
+}
+
+impl ZeroShareContribution {
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+}
+
+/// Generates `party_index`'s contribution to a proactive zero-sharing
+/// round. `pairwise_seeds` must hold, for every other committee member's
+/// index, the same seed that member also holds for `party_index` (agreed
+/// out of band the same way `cr_seed` itself is agreed).
+///
+/// Handing secrets to a new committee reuses this same zero-sharing
+/// primitive for the *outgoing* members (so the old shares keep summing to
+/// the unchanged ideal secret while the hand-off is in flight); actually
+/// redistributing shares to a disjoint set of *incoming* members who hold
+/// no prior share needs a genuine resharing sub-protocol on top of this,
+/// which is out of scope here.
+pub fn generate_zero_share_contribution(
+    party_index: usize,
+    ring_size: usize,
+    bound: u64,
+    pairwise_seeds: &HashMap<usize, [u8; 32]>,
+) -> ZeroShareContribution {
+    let mut values = vec![0i64; ring_size];
+    pairwise_seeds.iter().for_each(|(&other, seed)| {
+        let mut prng = DefaultSecureRng::new_with_seed(*seed);
+        let mut r = vec![0u64; ring_size];
+        RandomUniformDist::random_fill(&mut prng, &bound, r.as_mut_slice());
+
+        let sign = if party_index < other { 1i64 } else { -1i64 };
+        izip!(values.iter_mut(), r.iter()).for_each(|(v, r_j)| *v += sign * (*r_j as i64));
+    });
+    ZeroShareContribution { values }
+}
+
+/// Verifies that a full set of per-party [`ZeroShareContribution`]s (one
+/// per committee member) sums to the zero polynomial, which holds
+/// automatically for honestly-generated pairwise contributions. Run this
+/// as a sanity check before trusting a refresh round, mirroring the
+/// commitment/complaint checks used elsewhere in this file for the
+/// one-shot share flow.
+pub fn assert_zero_share_round_is_zero(contributions: &[ZeroShareContribution]) {
+    assert!(!contributions.is_empty(), "need at least one contribution");
+    let ring_size = contributions[0].values.len();
+
+    let mut total = vec![0i64; ring_size];
+    contributions.iter().for_each(|c| {
+        assert_eq!(c.values.len(), ring_size);
+        izip!(total.iter_mut(), c.values.iter()).for_each(|(t, v)| *t += v);
+    });
+    assert!(
+        total.iter().all(|&v| v == 0),
+        "zero-share round did not sum to zero; the collective secret would shift under this refresh"
+    );
+}
+
+/// Adds a proactive-refresh zero-share into an additive committee member's
+/// RLWE secret in place. The secret stays ternary-distributed on average
+/// only in the sense that the *ideal* `Σ s_i` is unchanged
+/// (see [`assert_zero_share_round_is_zero`]); an individual refreshed share
+/// is no longer ternary itself, same as a `ThresholdRlweSecretShare`.
+pub fn refresh_rlwe_secret_share(secret: &mut RlweSecret, contribution: &ZeroShareContribution) {
+    assert_eq!(secret.values.len(), contribution.values.len());
+    izip!(secret.values.iter_mut(), contribution.values.iter())
+        .for_each(|(s, c)| *s += i32::try_from(*c).expect("zero-share contribution overflowed i32; lower `bound`"));
+}
+
+/// Adds a proactive-refresh zero-share into a Shamir committee member's
+/// secret share in place (see [`refresh_rlwe_secret_share`] for the
+/// additive-sharing equivalent).
+pub fn refresh_threshold_share(
+    share: &mut ThresholdRlweSecretShare,
+    contribution: &ZeroShareContribution,
+) {
+    assert_eq!(share.values.len(), contribution.values.len());
+    izip!(share.values.iter_mut(), contribution.values.iter()).for_each(|(s, c)| *s += c);
+}
+
 /// Public key
 pub struct PublicKey<M, Rng, ModOp> {
     key: M,
@@ -288,6 +764,40 @@ mod impl_seeded_pk {
     }
 }
 
+impl<Ro, ModOp> SeededPublicKey<Ro, [u8; 32], BoolParameters<Ro::Element>, ModOp>
+where
+    Ro: RowEntity + RowMut,
+    Ro::Element: Copy + ToPrimitive + FromPrimitive,
+{
+    /// Serializes to seed || len(part_b) || part_b. `A` is re-sampled by
+    /// `PublicKey::from` from the seed, so only the seed and `part_b` ever
+    /// need to cross the wire. `parameters` is assumed already shared
+    /// out-of-band between parties and is supplied again to `from_bytes`
+    /// rather than serialized here.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(32 + 8 + self.part_b.as_ref().len() * 8);
+        out.extend_from_slice(&self.seed);
+        write_len(&mut out, self.part_b.as_ref().len());
+        write_row(&mut out, self.part_b.as_ref());
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8], parameters: BoolParameters<Ro::Element>) -> Self {
+        let mut at = 0;
+        let seed = read_seed(bytes, &mut at);
+        let len = read_len(bytes, &mut at);
+        let mut part_b = Ro::zeros(len);
+        read_row(bytes, &mut at, part_b.as_mut());
+
+        Self {
+            part_b,
+            seed,
+            parameters,
+            _phantom: PhantomData,
+        }
+    }
+}
+
 /// CRS seeded collective public key share
 pub struct CommonReferenceSeededCollectivePublicKeyShare<Ro, S, P> {
     share: Ro,
@@ -304,6 +814,265 @@ impl<Ro, S, P> CommonReferenceSeededCollectivePublicKeyShare<Ro, S, P> {
     }
 }
 
+impl<Ro> CommonReferenceSeededCollectivePublicKeyShare<Ro, [u8; 32], BoolParameters<Ro::Element>>
+where
+    Ro: RowEntity + RowMut,
+    Ro::Element: Copy + ToPrimitive + FromPrimitive,
+{
+    /// Serializes to cr_seed || len(share) || share.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(32 + 8 + self.share.as_ref().len() * 8);
+        out.extend_from_slice(&self.cr_seed);
+        write_len(&mut out, self.share.as_ref().len());
+        write_row(&mut out, self.share.as_ref());
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8], parameters: BoolParameters<Ro::Element>) -> Self {
+        let mut at = 0;
+        let cr_seed = read_seed(bytes, &mut at);
+        let len = read_len(bytes, &mut at);
+        let mut share = Ro::zeros(len);
+        read_row(bytes, &mut at, share.as_mut());
+
+        Self {
+            share,
+            cr_seed,
+            parameters,
+        }
+    }
+
+    /// Commits to this share for the DKG complaint round; broadcast the
+    /// returned value before revealing the share itself.
+    pub fn commit(&self) -> ShareCommitment {
+        hash_bytes(&self.to_bytes())
+    }
+}
+
+/// Largest magnitude a genuine secret/error coefficient can take in this
+/// scheme (ternary secret, bounded discrete-Gaussian error). The
+/// sigma-protocol masks are sampled from a range 2^10 times wider so
+/// `z = y + c*s_i`/`w = f + c*e_i` reveal nothing about `s_i`/`e_i`, while
+/// still landing comfortably below `q/2` so the verifier's norm check has
+/// room to tell a masked-but-honest opening from a forged one.
+const SHARE_PROOF_SECRET_BOUND: u64 = 1 << 10;
+/// Mask bound used when sampling `y`/`f`; see [`SHARE_PROOF_SECRET_BOUND`].
+const SHARE_PROOF_MASK_BOUND: u64 = 1 << 20;
+/// Norm bound the verifier enforces on the opened `z`/`w`: a well-formed
+/// proof never exceeds `mask bound + secret bound`, so anything above that
+/// is rejected outright regardless of whether the linear relation holds.
+const SHARE_PROOF_OPEN_BOUND: u64 = SHARE_PROOF_MASK_BOUND + SHARE_PROOF_SECRET_BOUND;
+
+/// Number of independent rounds [`ShareProof`] repeats the sigma protocol
+/// for. Each round's Fiat-Shamir challenge is a single bit, so a cheating
+/// prover who doesn't know a valid `(s_i, e_i)` still passes any one round
+/// with probability 1/2; repeating [`SHARE_PROOF_ROUNDS`] times independently
+/// (with fresh masks and a round-indexed, domain-separated challenge each
+/// time) drives the forgery probability down to `2^-SHARE_PROOF_ROUNDS`.
+const SHARE_PROOF_ROUNDS: usize = 40;
+
+/// A single round of the [`ShareProof`] sigma protocol; see there.
+struct ShareProofRound<Ro> {
+    /// Commitment `t = A*y + f`.
+    t: Ro,
+    /// Response `z = y + c*s_i`.
+    z: Ro,
+    /// Response `w = f + c*e_i`.
+    w: Ro,
+}
+
+/// Fiat-Shamir sigma protocol transcript proving a collective public-key
+/// (or RGSW/auto-key) share `part_b = A*s_i + e_i` is an honest RLWE
+/// encryption of zero under a small, known `(s_i, e_i)`, without revealing
+/// either. Each round's challenge is a single bit derived from
+/// [`share_proof_challenge`]; [`SHARE_PROOF_ROUNDS`] independent rounds are
+/// run and all must verify, so the soundness error is `2^-SHARE_PROOF_ROUNDS`
+/// rather than one bit.
+pub struct ShareProof<Ro> {
+    rounds: Vec<ShareProofRound<Ro>>,
+}
+
+/// Lifts a mod-`q` ring element to its signed representative in
+/// `(-q/2, q/2]`. Single-modulus analogue of `RnsModulus::signed_balanced_lift`;
+/// implemented freshly here since `RnsModulus` is a different (multi-prime
+/// CRT) structure not warranted for a single modulus.
+fn balanced_lift<E: ToPrimitive>(x: E, q: u64) -> i64 {
+    let x = x.to_u64().expect("ring element must fit in u64");
+    if x > q / 2 {
+        x as i64 - q as i64
+    } else {
+        x as i64
+    }
+}
+
+/// Samples a row of coefficients uniformly from `[0, bound)`, used for the
+/// sigma-protocol masks `y`/`f`. Values in this range double as small
+/// elements of `Z_q` (`bound` is always far below `q`), so no centering is
+/// needed before feeding them into ring arithmetic mod `q`.
+fn sample_masking_row<Ro>(ring_size: usize, bound: u64) -> Ro
+where
+    Ro: RowEntity + RowMut,
+    Ro::Element: FromPrimitive,
+{
+    let bound = Ro::Element::from_u64(bound).expect("mask bound must fit the ring element type");
+    DefaultSecureRng::with_local_mut(|rng| {
+        let mut row = Ro::zeros(ring_size);
+        RandomUniformDist::random_fill(rng, &bound, row.as_mut());
+        row
+    })
+}
+
+/// `out = a*x + e` in the ring, computed by lifting `a` and `x` into the
+/// NTT evaluation domain, multiplying pointwise, and adding the (coefficient
+/// domain) error back in -- the same shape as the `a_i * s` step in
+/// `secret_key_encrypt_rgsw`.
+fn ring_mul_add<Ro, ModOp, NttOp>(a: &Ro, x: &Ro, e: &Ro, mod_op: &ModOp, ntt_op: &NttOp) -> Ro
+where
+    Ro: RowEntity + RowMut,
+    Ro::Element: Copy,
+    ModOp: VectorOps<Element = Ro::Element>,
+    NttOp: Ntt<Element = Ro::Element>,
+{
+    let ring_size = a.as_ref().len();
+
+    let mut a_eval = Ro::zeros(ring_size);
+    a_eval.as_mut().copy_from_slice(a.as_ref());
+    ntt_op.forward(a_eval.as_mut());
+
+    let mut x_eval = Ro::zeros(ring_size);
+    x_eval.as_mut().copy_from_slice(x.as_ref());
+    ntt_op.forward(x_eval.as_mut());
+
+    mod_op.elwise_mul_mut(a_eval.as_mut(), x_eval.as_ref());
+    ntt_op.backward(a_eval.as_mut());
+
+    mod_op.elwise_add_mut(a_eval.as_mut(), e.as_ref());
+    a_eval
+}
+
+/// Derives the Fiat-Shamir challenge bit for one round of a share proof by
+/// hashing the CRS seed together with the statement (`part_b`), the
+/// prover's commitment (`t`), and the round index (so the
+/// [`SHARE_PROOF_ROUNDS`] rounds of the same proof can't be copied from one
+/// another). Reuses [`hash_bytes`]'s SHA-256.
+fn share_proof_challenge<E: ToPrimitive + Copy>(
+    cr_seed: &[u8; 32],
+    part_b: &[E],
+    t: &[E],
+    round: usize,
+) -> u64 {
+    let mut bytes = Vec::with_capacity(32 + (part_b.len() + t.len()) * 8 + 8);
+    bytes.extend_from_slice(cr_seed);
+    write_row(&mut bytes, part_b);
+    write_row(&mut bytes, t);
+    write_len(&mut bytes, round);
+    (hash_bytes(&bytes).0[0] & 1) as u64
+}
+
+/// Reconstructs the CRS-derived `A` row for a collective public-key share,
+/// i.e. the same sampling `PublicKey::from(&[...shares])` performs.
+fn reconstruct_share_a<Ro, Rng>(cr_seed: [u8; 32], rlwe_q: &CiphertextModulus<Ro::Element>, ring_size: usize) -> Ro
+where
+    Ro: RowEntity + RowMut,
+    Rng: NewWithSeed<Seed = [u8; 32]> + RandomFillUniformInModulus<[Ro::Element], CiphertextModulus<Ro::Element>>,
+{
+    let mut a = Ro::zeros(ring_size);
+    let mut prng = Rng::new_with_seed(cr_seed);
+    RandomFillUniformInModulus::random_fill(&mut prng, rlwe_q, a.as_mut());
+    a
+}
+
+impl<Ro> CommonReferenceSeededCollectivePublicKeyShare<Ro, [u8; 32], BoolParameters<Ro::Element>>
+where
+    Ro: RowEntity + RowMut,
+    Ro::Element: Copy + ToPrimitive + FromPrimitive,
+{
+    /// Proves that `self.share = A*s_i + e_i` for the given (small, known)
+    /// `s_i`/`e_i`, without revealing either.
+    pub fn prove_share<Rng, ModOp, NttOp>(
+        &self,
+        s_i: &Ro,
+        e_i: &Ro,
+        mod_op: &ModOp,
+        ntt_op: &NttOp,
+    ) -> ShareProof<Ro>
+    where
+        Rng: NewWithSeed<Seed = [u8; 32]>
+            + RandomFillUniformInModulus<[Ro::Element], CiphertextModulus<Ro::Element>>,
+        ModOp: VectorOps<Element = Ro::Element>,
+        NttOp: Ntt<Element = Ro::Element>,
+    {
+        let ring_size = self.share.as_ref().len();
+        let a = reconstruct_share_a::<Ro, Rng>(self.cr_seed, &self.parameters.rlwe_q(), ring_size);
+
+        let rounds = (0..SHARE_PROOF_ROUNDS)
+            .map(|round| {
+                let mut y = sample_masking_row::<Ro>(ring_size, SHARE_PROOF_MASK_BOUND);
+                let mut f = sample_masking_row::<Ro>(ring_size, SHARE_PROOF_MASK_BOUND);
+                let t = ring_mul_add(&a, &y, &f, mod_op, ntt_op);
+
+                let c = share_proof_challenge(&self.cr_seed, self.share.as_ref(), t.as_ref(), round);
+                if c == 1 {
+                    mod_op.elwise_add_mut(y.as_mut(), s_i.as_ref());
+                    mod_op.elwise_add_mut(f.as_mut(), e_i.as_ref());
+                }
+
+                ShareProofRound { t, z: y, w: f }
+            })
+            .collect();
+
+        ShareProof { rounds }
+    }
+
+    /// Verifies a [`ShareProof`] produced by [`Self::prove_share`]: checks
+    /// the linear relation `A*z + w == t + c*part_b` and that `z`/`w` carry
+    /// small (balanced-lifted) coefficients, i.e. the prover didn't skip
+    /// masking to smuggle an arbitrary opening through.
+    pub fn verify_share<Rng, ModOp, NttOp>(
+        &self,
+        proof: &ShareProof<Ro>,
+        mod_op: &ModOp,
+        ntt_op: &NttOp,
+    ) -> bool
+    where
+        Rng: NewWithSeed<Seed = [u8; 32]>
+            + RandomFillUniformInModulus<[Ro::Element], CiphertextModulus<Ro::Element>>,
+        ModOp: VectorOps<Element = Ro::Element>,
+        NttOp: Ntt<Element = Ro::Element>,
+    {
+        if proof.rounds.len() != SHARE_PROOF_ROUNDS {
+            return false;
+        }
+
+        let ring_size = self.share.as_ref().len();
+        let q = mod_op.modulus();
+        let q_u64 = q.to_u64().expect("ring modulus must fit in u64");
+        let a = reconstruct_share_a::<Ro, Rng>(self.cr_seed, &self.parameters.rlwe_q(), ring_size);
+
+        proof.rounds.iter().enumerate().all(|(round, r)| {
+            let in_bound = izip!(r.z.as_ref().iter(), r.w.as_ref().iter()).all(|(z, w)| {
+                balanced_lift(*z, q_u64).unsigned_abs() <= SHARE_PROOF_OPEN_BOUND
+                    && balanced_lift(*w, q_u64).unsigned_abs() <= SHARE_PROOF_OPEN_BOUND
+            });
+            if !in_bound {
+                return false;
+            }
+
+            let c = share_proof_challenge(&self.cr_seed, self.share.as_ref(), r.t.as_ref(), round);
+
+            let lhs = ring_mul_add(&a, &r.z, &r.w, mod_op, ntt_op);
+
+            let mut rhs = Ro::zeros(ring_size);
+            rhs.as_mut().copy_from_slice(r.t.as_ref());
+            if c == 1 {
+                mod_op.elwise_add_mut(rhs.as_mut(), self.share.as_ref());
+            }
+
+            lhs.as_ref() == rhs.as_ref()
+        })
+    }
+}
+
 /// CRS seeded Multi-party server key share
 pub struct CommonReferenceSeededMultiPartyServerKeyShare<M: Matrix, P, S> {
     rgsw_cts: Vec<M>,
@@ -354,6 +1123,154 @@ impl<M: Matrix, P, S> CommonReferenceSeededMultiPartyServerKeyShare<M, P, S> {
     }
 }
 
+impl<M> CommonReferenceSeededMultiPartyServerKeyShare<M, BoolParameters<M::MatElement>, [u8; 32]>
+where
+    M: MatrixMut + MatrixEntity,
+    M::R: RowMut + RowEntity + Clone,
+    M::MatElement: Copy + ToPrimitive + FromPrimitive,
+{
+    /// Serializes to cr_seed || rgsw_cts || auto_keys || lwe_ksk.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&self.cr_seed);
+        write_matrix_vec(&mut out, &self.rgsw_cts);
+        write_auto_keys(&mut out, &self.auto_keys);
+        write_len(&mut out, self.lwe_ksk.as_ref().len());
+        write_row(&mut out, self.lwe_ksk.as_ref());
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8], parameters: BoolParameters<M::MatElement>) -> Self {
+        let mut at = 0;
+        let cr_seed = read_seed(bytes, &mut at);
+        let rgsw_cts = read_matrix_vec(bytes, &mut at);
+        let auto_keys = read_auto_keys(bytes, &mut at);
+        let lwe_ksk_len = read_len(bytes, &mut at);
+        let mut lwe_ksk = M::R::zeros(lwe_ksk_len);
+        read_row(bytes, &mut at, lwe_ksk.as_mut());
+
+        Self {
+            rgsw_cts,
+            auto_keys,
+            lwe_ksk,
+            cr_seed,
+            parameters,
+        }
+    }
+
+    /// Commits to this share for the DKG complaint round; broadcast the
+    /// returned value before revealing the share itself.
+    pub fn commit(&self) -> ShareCommitment {
+        hash_bytes(&self.to_bytes())
+    }
+
+    /// Proves every row of `rgsw_cts`/`auto_keys` is an honest RLWE
+    /// encryption of zero under the same (small, known) `s_i`: each row is
+    /// itself exactly the statement
+    /// [`CommonReferenceSeededCollectivePublicKeyShare::prove_share`] proves,
+    /// just against a row-specific `A` derived from this share's `cr_seed`
+    /// plus the row's position (see [`row_cr_seed`]/[`server_key_share_rows`])
+    /// instead of the `cr_seed` directly.
+    ///
+    /// `rgsw_errors`/`auto_key_errors` must have the same shape as
+    /// `self.rgsw_cts`/`self.auto_keys` (same matrix dimensions, same
+    /// `auto_keys` key set) and hold the `e_i` actually used to encrypt each
+    /// row -- the same values [`Self::verify_rows`] checks were used
+    /// honestly, without either ever being revealed directly.
+    pub fn prove_rows<Rng, ModOp, NttOp>(
+        &self,
+        s_i: &M::R,
+        rgsw_errors: &[M],
+        auto_key_errors: &HashMap<usize, M>,
+        mod_op: &ModOp,
+        ntt_op: &NttOp,
+    ) -> Vec<ShareProof<M::R>>
+    where
+        Rng: NewWithSeed<Seed = [u8; 32]>
+            + RandomFillUniformInModulus<[M::MatElement], CiphertextModulus<M::MatElement>>,
+        ModOp: VectorOps<Element = M::MatElement>,
+        NttOp: Ntt<Element = M::MatElement>,
+    {
+        let rows = server_key_share_rows(&self.rgsw_cts, &self.auto_keys);
+        let error_rows = server_key_share_rows(rgsw_errors, auto_key_errors);
+        izip!(rows, error_rows)
+            .enumerate()
+            .map(|(index, (row, e_row))| {
+                let row_share = CommonReferenceSeededCollectivePublicKeyShare::new(
+                    row.clone(),
+                    row_cr_seed(&self.cr_seed, index),
+                    self.parameters.clone(),
+                );
+                row_share.prove_share::<Rng, _, _>(s_i, e_row, mod_op, ntt_op)
+            })
+            .collect()
+    }
+
+    /// Verifies every proof in `proofs` against this share's rows, in the
+    /// same flattened order [`Self::prove_rows`] produced them
+    /// ([`server_key_share_rows`]). Returns `true` only if every row checks
+    /// out, i.e. this is the RGSW-ciphertext/auto-key-row analogue of
+    /// [`CommonReferenceSeededCollectivePublicKeyShare::verify_share`].
+    pub fn verify_rows<Rng, ModOp, NttOp>(
+        &self,
+        proofs: &[ShareProof<M::R>],
+        mod_op: &ModOp,
+        ntt_op: &NttOp,
+    ) -> bool
+    where
+        Rng: NewWithSeed<Seed = [u8; 32]>
+            + RandomFillUniformInModulus<[M::MatElement], CiphertextModulus<M::MatElement>>,
+        ModOp: VectorOps<Element = M::MatElement>,
+        NttOp: Ntt<Element = M::MatElement>,
+    {
+        let rows: Vec<&M::R> = server_key_share_rows(&self.rgsw_cts, &self.auto_keys).collect();
+        if rows.len() != proofs.len() {
+            return false;
+        }
+
+        izip!(rows.iter(), proofs.iter())
+            .enumerate()
+            .all(|(index, (row, proof))| {
+                let row_share = CommonReferenceSeededCollectivePublicKeyShare::new(
+                    (*row).clone(),
+                    row_cr_seed(&self.cr_seed, index),
+                    self.parameters.clone(),
+                );
+                row_share.verify_share::<Rng, _, _>(proof, mod_op, ntt_op)
+            })
+    }
+}
+
+/// Derives a row's own `cr_seed` (and therefore its own `A`) from a party's
+/// base `cr_seed` plus the row's position in [`server_key_share_rows`]'s
+/// flattened ordering, so that `prove_rows`/`verify_rows` check every row
+/// against its own `A`.
+fn row_cr_seed(base: &[u8; 32], index: usize) -> [u8; 32] {
+    let mut bytes = Vec::with_capacity(32 + 8);
+    bytes.extend_from_slice(base);
+    write_len(&mut bytes, index);
+    hash_bytes(&bytes).0
+}
+
+/// Flattens `rgsw_cts`/`auto_keys` into the single, deterministic row
+/// ordering [`row_cr_seed`]'s index, [`CommonReferenceSeededMultiPartyServerKeyShare::prove_rows`]
+/// and `verify_rows` all rely on: every row of every `rgsw_cts` matrix (in
+/// order), followed by every row of every `auto_keys` matrix sorted by key
+/// (`HashMap` iteration order isn't stable, so iterating it directly would
+/// make the prover and verifier disagree on which row is which).
+fn server_key_share_rows<'a, M: Matrix>(
+    rgsw_cts: &'a [M],
+    auto_keys: &'a HashMap<usize, M>,
+) -> impl Iterator<Item = &'a M::R> + 'a {
+    let mut auto_key_mats: Vec<(&usize, &M)> = auto_keys.iter().collect();
+    auto_key_mats.sort_unstable_by_key(|(k, _)| **k);
+
+    rgsw_cts
+        .iter()
+        .flat_map(|m| m.iter_rows())
+        .chain(auto_key_mats.into_iter().flat_map(|(_, m)| m.iter_rows()))
+}
+
 /// CRS seeded MultiParty server key
 pub struct SeededMultiPartyServerKey<M: Matrix, S, P> {
     rgsw_cts: Vec<M>,
@@ -387,6 +1304,246 @@ impl<M: Matrix, S, P> SeededMultiPartyServerKey<M, S, P> {
     }
 }
 
+impl<M> SeededMultiPartyServerKey<M, [u8; 32], BoolParameters<M::MatElement>>
+where
+    M: MatrixMut + MatrixEntity,
+    M::R: RowMut + RowEntity,
+    M::MatElement: Copy + ToPrimitive + FromPrimitive,
+{
+    /// Serializes to cr_seed || rgsw_cts || auto_keys || lwe_ksk.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&self.cr_seed);
+        write_matrix_vec(&mut out, &self.rgsw_cts);
+        write_auto_keys(&mut out, &self.auto_keys);
+        write_len(&mut out, self.lwe_ksk.as_ref().len());
+        write_row(&mut out, self.lwe_ksk.as_ref());
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8], parameters: BoolParameters<M::MatElement>) -> Self {
+        let mut at = 0;
+        let cr_seed = read_seed(bytes, &mut at);
+        let rgsw_cts = read_matrix_vec(bytes, &mut at);
+        let auto_keys = read_auto_keys(bytes, &mut at);
+        let lwe_ksk_len = read_len(bytes, &mut at);
+        let mut lwe_ksk = M::R::zeros(lwe_ksk_len);
+        read_row(bytes, &mut at, lwe_ksk.as_mut());
+
+        Self {
+            rgsw_cts,
+            auto_keys,
+            lwe_ksk,
+            cr_seed,
+            parameters,
+        }
+    }
+}
+
+/// Sums qualified parties' RGSW/auto-key/lwe-ksk shares into the collective
+/// seeded server key; mirrors `SeededPublicKey`'s sum-of-shares assembly,
+/// generalized from a single row to full matrices and a `HashMap` of auto
+/// keys. Not a `From` impl because `SeededMultiPartyServerKey` carries no
+/// `ModOp` type parameter for the modular-arithmetic backend to attach to.
+fn aggregate_server_key_shares<M, ModOp>(
+    shares: &[CommonReferenceSeededMultiPartyServerKeyShare<
+        M,
+        BoolParameters<M::MatElement>,
+        [u8; 32],
+    >],
+) -> SeededMultiPartyServerKey<M, [u8; 32], BoolParameters<M::MatElement>>
+where
+    M: MatrixMut + MatrixEntity,
+    M::R: RowMut + RowEntity,
+    M::MatElement: Copy + PartialEq,
+    ModOp: VectorOps<Element = M::MatElement> + ModInit<M = CiphertextModulus<M::MatElement>>,
+{
+    assert!(!shares.is_empty());
+
+    let parameters = shares[0].parameters().clone();
+    let cr_seed = *shares[0].cr_seed();
+
+    let rlweq_modop = ModOp::new(parameters.rlwe_q().clone());
+    let lweq_modop = ModOp::new(parameters.lwe_q().clone());
+
+    let mut rgsw_cts: Vec<M> = shares[0]
+        .rgsw_cts()
+        .iter()
+        .map(|m| {
+            let (rows, cols) = m.dimension();
+            M::zeros(rows, cols)
+        })
+        .collect();
+    let mut auto_keys: HashMap<usize, M> = shares[0]
+        .auto_keys()
+        .iter()
+        .map(|(k, m)| {
+            let (rows, cols) = m.dimension();
+            (*k, M::zeros(rows, cols))
+        })
+        .collect();
+    let mut lwe_ksk = M::R::zeros(shares[0].lwe_ksk().as_ref().len());
+
+    shares.iter().for_each(|share_i| {
+        assert!(share_i.cr_seed() == &cr_seed);
+        assert!(share_i.parameters() == &parameters);
+
+        izip!(rgsw_cts.iter_mut(), share_i.rgsw_cts().iter()).for_each(|(acc, part_i)| {
+            izip!(acc.iter_rows_mut(), part_i.iter_rows()).for_each(|(acc_row, part_row)| {
+                rlweq_modop.elwise_add_mut(acc_row.as_mut(), part_row.as_ref());
+            });
+        });
+
+        share_i.auto_keys().iter().for_each(|(k, part_i)| {
+            let acc = auto_keys
+                .get_mut(k)
+                .expect("auto key index missing from an earlier share");
+            izip!(acc.iter_rows_mut(), part_i.iter_rows()).for_each(|(acc_row, part_row)| {
+                rlweq_modop.elwise_add_mut(acc_row.as_mut(), part_row.as_ref());
+            });
+        });
+
+        lweq_modop.elwise_add_mut(lwe_ksk.as_mut(), share_i.lwe_ksk().as_ref());
+    });
+
+    SeededMultiPartyServerKey {
+        rgsw_cts,
+        auto_keys,
+        lwe_ksk,
+        cr_seed,
+        parameters,
+    }
+}
+
+/// Aggregates collective public-key shares gathered from a synchronous
+/// commit-then-reveal DKG round. `commitments[i]` must be the
+/// `ShareCommitment` party `i` broadcast before revealing `revealed[i]`. A
+/// party whose revealed share doesn't hash back to its commitment, or whose
+/// `cr_seed`/`parameters` disagree with the rest of the group, is dropped
+/// from the aggregation; its index is returned alongside the key so the
+/// caller can report a complaint against it, rather than the blind
+/// `From<&[..]>` assembly silently folding a malformed share into the
+/// collective key.
+pub fn aggregate_collective_pk_shares_with_complaints<M, Rng, ModOp, NttOp>(
+    commitments: &[ShareCommitment],
+    revealed: Vec<
+        CommonReferenceSeededCollectivePublicKeyShare<M::R, [u8; 32], BoolParameters<M::MatElement>>,
+    >,
+    proofs: &[ShareProof<M::R>],
+    mod_op: &ModOp,
+    ntt_op: &NttOp,
+) -> (PublicKey<M, Rng, ModOp>, Vec<usize>)
+where
+    M: MatrixMut + MatrixEntity,
+    Rng: NewWithSeed<Seed = [u8; 32]>
+        + RandomFillUniformInModulus<[M::MatElement], CiphertextModulus<M::MatElement>>,
+    ModOp: VectorOps<Element = M::MatElement> + ModInit<M = CiphertextModulus<M::MatElement>>,
+    NttOp: Ntt<Element = M::MatElement>,
+    M::R: RowMut + RowEntity,
+    M::MatElement: PartialEq + Copy + ToPrimitive + FromPrimitive,
+{
+    assert_eq!(revealed.len(), proofs.len());
+
+    let (mut qualified_idx, mut excluded) = qualify_shares(
+        commitments,
+        &revealed,
+        |s| s.commit(),
+        |a, b| a.cr_seed == b.cr_seed,
+        |a, b| a.parameters == b.parameters,
+    );
+
+    // A commitment/reveal mismatch and a forged `part_b` are different
+    // complaints, but both disqualify the share in the same way, so fold
+    // proof verification into the same exclusion list.
+    qualified_idx.retain(|&idx| {
+        let honest = revealed[idx].verify_share::<Rng, _, _>(&proofs[idx], mod_op, ntt_op);
+        if !honest {
+            excluded.push(idx);
+        }
+        honest
+    });
+    excluded.sort_unstable();
+
+    assert!(
+        !qualified_idx.is_empty(),
+        "every revealed collective pk share failed the commitment/complaint or proof check"
+    );
+
+    let qualified: Vec<_> = revealed
+        .into_iter()
+        .enumerate()
+        .filter(|(idx, _)| qualified_idx.contains(idx))
+        .map(|(_, share)| share)
+        .collect();
+
+    let pk = PublicKey::<M, Rng, ModOp>::from(qualified.as_slice());
+    (pk, excluded)
+}
+
+/// Server-key-share analogue of
+/// [`aggregate_collective_pk_shares_with_complaints`]: runs the same
+/// commit-then-reveal complaint round and sums only the qualified parties'
+/// RGSW/auto-key/lwe-ksk shares into the collective `SeededMultiPartyServerKey`.
+pub fn aggregate_server_key_shares_with_complaints<M, Rng, ModOp, NttOp>(
+    commitments: &[ShareCommitment],
+    revealed: Vec<
+        CommonReferenceSeededMultiPartyServerKeyShare<M, BoolParameters<M::MatElement>, [u8; 32]>,
+    >,
+    proofs: &[Vec<ShareProof<M::R>>],
+    mod_op: &ModOp,
+    ntt_op: &NttOp,
+) -> (
+    SeededMultiPartyServerKey<M, [u8; 32], BoolParameters<M::MatElement>>,
+    Vec<usize>,
+)
+where
+    M: MatrixMut + MatrixEntity,
+    M::R: RowMut + RowEntity + Clone,
+    M::MatElement: Copy + PartialEq + ToPrimitive + FromPrimitive,
+    Rng: NewWithSeed<Seed = [u8; 32]>
+        + RandomFillUniformInModulus<[M::MatElement], CiphertextModulus<M::MatElement>>,
+    ModOp: VectorOps<Element = M::MatElement> + ModInit<M = CiphertextModulus<M::MatElement>>,
+    NttOp: Ntt<Element = M::MatElement>,
+{
+    assert_eq!(revealed.len(), proofs.len());
+
+    let (mut qualified_idx, mut excluded) = qualify_shares(
+        commitments,
+        &revealed,
+        |s| s.commit(),
+        |a, b| a.cr_seed() == b.cr_seed(),
+        |a, b| a.parameters() == b.parameters(),
+    );
+
+    // A commitment/reveal mismatch and a forged RGSW/auto-key row are
+    // different complaints, but both disqualify the share the same way --
+    // fold the per-row proof check into the same exclusion list, mirroring
+    // `aggregate_collective_pk_shares_with_complaints`.
+    qualified_idx.retain(|&idx| {
+        let honest = revealed[idx].verify_rows::<Rng, _, _>(&proofs[idx], mod_op, ntt_op);
+        if !honest {
+            excluded.push(idx);
+        }
+        honest
+    });
+    excluded.sort_unstable();
+
+    assert!(
+        !qualified_idx.is_empty(),
+        "every revealed server key share failed the commitment/complaint or proof check"
+    );
+
+    let qualified: Vec<_> = revealed
+        .into_iter()
+        .enumerate()
+        .filter(|(idx, _)| qualified_idx.contains(idx))
+        .map(|(_, share)| share)
+        .collect();
+
+    let key = aggregate_server_key_shares::<M, ModOp>(&qualified);
+    (key, excluded)
+}
+
 /// Seeded single party server key
 pub struct SeededSinglePartyServerKey<M: Matrix, P, S> {
     /// Rgsw cts of LWE secret elements
@@ -439,6 +1596,73 @@ impl<M: Matrix, S> SeededSinglePartyServerKey<M, BoolParameters<M::MatElement>,
     }
 }
 
+impl<M> SeededSinglePartyServerKey<M, BoolParameters<M::MatElement>, [u8; 32]>
+where
+    M: MatrixMut + MatrixEntity,
+    M::R: RowMut + RowEntity,
+    M::MatElement: Copy + ToPrimitive + FromPrimitive,
+{
+    /// Serializes to seed || rgsw_cts || auto_keys || lwe_ksk, i.e.
+    /// everything the seeded key carries beyond the uniform part that
+    /// `ServerKeyEvaluationDomain::from` re-samples from the seed.
+    /// `parameters` is assumed already known to the receiver and is passed
+    /// back in to `from_bytes` instead of being serialized.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&self.seed);
+        write_matrix_vec(&mut out, &self.rgsw_cts);
+        write_auto_keys(&mut out, &self.auto_keys);
+        write_len(&mut out, self.lwe_ksk.as_ref().len());
+        write_row(&mut out, self.lwe_ksk.as_ref());
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8], parameters: BoolParameters<M::MatElement>) -> Self {
+        let mut at = 0;
+        let seed = read_seed(bytes, &mut at);
+        let rgsw_cts = read_matrix_vec(bytes, &mut at);
+        let auto_keys = read_auto_keys(bytes, &mut at);
+        let lwe_ksk_len = read_len(bytes, &mut at);
+        let mut lwe_ksk = M::R::zeros(lwe_ksk_len);
+        read_row(bytes, &mut at, lwe_ksk.as_mut());
+
+        Self {
+            rgsw_cts,
+            auto_keys,
+            lwe_ksk,
+            parameters,
+            seed,
+        }
+    }
+}
+
+/// Forward-transforms every row of `m` into the NTT evaluation domain, used
+/// by the `*EvaluationDomain` constructors below where rows are independent
+/// (no cross-row state from one `forward` call to the next). With the
+/// `parallel` feature enabled (the same feature gating the row-parallel RGSW
+/// product in `rgsw.rs`) the rows are farmed out to rayon; without it, it's
+/// the same serial per-row loop these constructors always ran.
+#[cfg(feature = "parallel")]
+fn forward_all_rows<M: MatrixMut, N: Ntt<Element = M::MatElement> + Sync>(m: &mut M, ntt_op: &N)
+where
+    M::R: RowMut + Send,
+{
+    use rayon::prelude::*;
+
+    m.iter_rows_mut()
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .for_each(|ri| ntt_op.forward(ri.as_mut()));
+}
+
+#[cfg(not(feature = "parallel"))]
+fn forward_all_rows<M: MatrixMut, N: Ntt<Element = M::MatElement>>(m: &mut M, ntt_op: &N)
+where
+    M::R: RowMut,
+{
+    m.iter_rows_mut().for_each(|ri| ntt_op.forward(ri.as_mut()));
+}
+
 /// Server key in evaluation domain
 pub(crate) struct ServerKeyEvaluationDomain<M, P, R, N> {
     /// Rgsw cts of LWE secret elements
@@ -518,8 +1742,7 @@ pub(super) mod impl_server_key_eval_domain {
                 .for_each(|(to_ri, from_ri)| to_ri.as_mut().copy_from_slice(from_ri.as_ref()));
 
                 // Send to Evaluation domain
-                data.iter_rows_mut()
-                    .for_each(|ri| nttop.forward(ri.as_mut()));
+                forward_all_rows(&mut data, &nttop);
 
                 auto_keys.insert(i, data);
             }
@@ -565,8 +1788,7 @@ pub(super) mod impl_server_key_eval_domain {
                     .for_each(|(to_ri, from_ri)| to_ri.as_mut().copy_from_slice(from_ri.as_ref()));
 
                     // send polynomials to evaluation domain
-                    data.iter_rows_mut()
-                        .for_each(|ri| nttop.forward(ri.as_mut()));
+                    forward_all_rows(&mut data, &nttop);
 
                     data
                 })
@@ -650,8 +1872,7 @@ pub(super) mod impl_server_key_eval_domain {
                 });
 
                 // send to evaluation domain
-                key.iter_rows_mut()
-                    .for_each(|ri| rlwe_nttop.forward(ri.as_mut()));
+                forward_all_rows(&mut key, &rlwe_nttop);
 
                 auto_keys.insert(i, key);
             }
@@ -771,10 +1992,9 @@ pub(super) mod impl_non_interactive_server_key_eval_domain {
             // RGSW cts
             // copy over rgsw cts and send to evaluation domain
             let mut rgsw_cts = value.rgsw_cts.clone();
-            rgsw_cts.iter_mut().for_each(|c| {
-                c.iter_rows_mut()
-                    .for_each(|ri| rlwe_nttop.forward(ri.as_mut()))
-            });
+            rgsw_cts
+                .iter_mut()
+                .for_each(|c| forward_all_rows(c, &rlwe_nttop));
 
             // Auto keys
             // populate pseudo random part of auto keys. Then send auto keys to
@@ -811,9 +2031,7 @@ pub(super) mod impl_non_interactive_server_key_eval_domain {
                 .for_each(|(to_ri, from_ri)| to_ri.as_mut().copy_from_slice(from_ri.as_ref()));
 
                 // send to evaluation domain
-                auto_ct
-                    .iter_rows_mut()
-                    .for_each(|r| rlwe_nttop.forward(r.as_mut()));
+                forward_all_rows(&mut auto_ct, &rlwe_nttop);
 
                 auto_keys.insert(*el, auto_ct);
             });
@@ -874,9 +2092,7 @@ pub(super) mod impl_non_interactive_server_key_eval_domain {
                         to_ri.as_mut().copy_from_slice(from_ri.as_ref());
                     });
 
-                    ksk_ct
-                        .iter_rows_mut()
-                        .for_each(|r| rlwe_nttop.forward(r.as_mut()));
+                    forward_all_rows(&mut ksk_ct, &rlwe_nttop);
                     ksk_ct
                 })
                 .collect_vec();
@@ -930,16 +2146,145 @@ impl<M: Matrix, S, P> SeededNonInteractiveMultiPartyServerKey<M, S, P> {
     }
 }
 
+impl<M>
+    SeededNonInteractiveMultiPartyServerKey<
+        M,
+        NonInteractiveMultiPartyCrs<[u8; 32]>,
+        BoolParameters<M::MatElement>,
+    >
+where
+    M: MatrixMut + MatrixEntity,
+    M::R: RowMut + RowEntity,
+    M::MatElement: Copy + ToPrimitive + FromPrimitive,
+{
+    /// Serializes to a small self-describing header (element width, ring
+    /// size, auto/RGSW/u_i->s decomposition counts, user count) followed by
+    /// `cr_seed`, `ui_to_s_ksks_key_order`, the RGSW cts, and the stored
+    /// part-B rows of `auto_keys`/`lwe_ksk`/`ui_to_s_ksks` -- everything the
+    /// corresponding `From<...> for NonInteractiveServerKeyEvaluationDomain`
+    /// can't regenerate on its own from `cr_seed`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let ring_size = self.parameters.rlwe_n().0;
+        let d_auto = self.parameters.auto_decomposition_count().0;
+        let (d_rgsw_a, d_rgsw_b) = self.parameters.rlwe_rgsw_decomposition_count();
+        let d_uitos = self
+            .parameters
+            .non_interactive_ui_to_s_key_switch_decomposition_count()
+            .0;
+
+        let mut out = Vec::new();
+        write_element(&mut out, std::mem::size_of::<M::MatElement>() as u64);
+        write_len(&mut out, ring_size);
+        write_len(&mut out, d_auto);
+        write_len(&mut out, d_rgsw_a.0);
+        write_len(&mut out, d_rgsw_b.0);
+        write_len(&mut out, d_uitos);
+        write_len(&mut out, self.ui_to_s_ksks_key_order.len());
+
+        out.extend_from_slice(&self.cr_seed.0);
+        self.ui_to_s_ksks_key_order
+            .iter()
+            .for_each(|idx| write_len(&mut out, *idx));
+
+        write_matrix_vec(&mut out, &self.rgsw_cts);
+        write_auto_keys(&mut out, &self.auto_keys);
+        write_matrix_vec(&mut out, &self.ui_to_s_ksks);
+        write_len(&mut out, self.lwe_ksk.as_ref().len());
+        write_row(&mut out, self.lwe_ksk.as_ref());
+
+        out
+    }
+
+    /// Parses bytes produced by [`Self::to_bytes`], checking the header
+    /// against `parameters` and the parsed matrices' dimensions against the
+    /// header up front instead of deep inside the later `From` conversion.
+    pub fn from_bytes(bytes: &[u8], parameters: BoolParameters<M::MatElement>) -> Self {
+        let mut at = 0;
+        let element_width: u64 = read_element(bytes, &mut at);
+        assert_eq!(
+            element_width,
+            std::mem::size_of::<M::MatElement>() as u64,
+            "ring element width mismatch between sender and receiver"
+        );
+
+        let ring_size = read_len(bytes, &mut at);
+        let d_auto = read_len(bytes, &mut at);
+        let d_rgsw_a = read_len(bytes, &mut at);
+        let d_rgsw_b = read_len(bytes, &mut at);
+        let d_uitos = read_len(bytes, &mut at);
+        let user_count = read_len(bytes, &mut at);
+
+        assert_eq!(ring_size, parameters.rlwe_n().0, "ring size mismatch");
+        assert_eq!(
+            d_auto,
+            parameters.auto_decomposition_count().0,
+            "auto decomposition count mismatch"
+        );
+        let (expect_rgsw_a, expect_rgsw_b) = parameters.rlwe_rgsw_decomposition_count();
+        assert_eq!(d_rgsw_a, expect_rgsw_a.0, "rgsw A decomposition count mismatch");
+        assert_eq!(d_rgsw_b, expect_rgsw_b.0, "rgsw B decomposition count mismatch");
+        assert_eq!(
+            d_uitos,
+            parameters
+                .non_interactive_ui_to_s_key_switch_decomposition_count()
+                .0,
+            "u_i -> s decomposition count mismatch"
+        );
+
+        let mut cr_seed_bytes = [0u8; 32];
+        cr_seed_bytes.copy_from_slice(&bytes[at..at + 32]);
+        at += 32;
+        let cr_seed = NonInteractiveMultiPartyCrs(cr_seed_bytes);
+
+        let ui_to_s_ksks_key_order: Vec<usize> =
+            (0..user_count).map(|_| read_len(bytes, &mut at)).collect();
+
+        let rgsw_cts: Vec<M> = read_matrix_vec(bytes, &mut at);
+        let auto_keys: HashMap<usize, M> = read_auto_keys(bytes, &mut at);
+        let ui_to_s_ksks: Vec<M> = read_matrix_vec(bytes, &mut at);
+
+        let lwe_ksk_len = read_len(bytes, &mut at);
+        let mut lwe_ksk = M::R::zeros(lwe_ksk_len);
+        read_row(bytes, &mut at, lwe_ksk.as_mut());
+
+        rgsw_cts.iter().for_each(|m| {
+            assert!(
+                m.dimension() == (d_rgsw_a * 2 + d_rgsw_b * 2, ring_size),
+                "rgsw ct dimension mismatch"
+            );
+        });
+        auto_keys.values().for_each(|m| {
+            assert!(m.dimension() == (d_auto, ring_size), "auto key dimension mismatch");
+        });
+        ui_to_s_ksks.iter().for_each(|m| {
+            assert!(
+                m.dimension() == (d_uitos, ring_size),
+                "u_i -> s ksk dimension mismatch"
+            );
+        });
+
+        Self {
+            ui_to_s_ksks,
+            ui_to_s_ksks_key_order,
+            rgsw_cts,
+            auto_keys,
+            lwe_ksk,
+            cr_seed,
+            parameters,
+        }
+    }
+}
+
 pub(crate) struct ShoupNonInteractiveServerKeyEvaluationDomain<M> {
     /// RGSW ciphertexts ideal lwe secret key elements under ideal rlwe secret
-    rgsw_cts: Vec<NormalAndShoup<M>>,
+    rgsw_cts: Vec<ShoupRepr<M>>,
     /// Automorphism keys under ideal rlwe secret
-    auto_keys: HashMap<usize, NormalAndShoup<M>>,
+    auto_keys: HashMap<usize, ShoupRepr<M>>,
     /// LWE key switching key from Q -> Q_{ks}
     lwe_ksk: M,
     /// Key switching key from user j to ideal secret key s. User j's ksk is at
     /// j'th element
-    ui_to_s_ksks: Vec<NormalAndShoup<M>>,
+    ui_to_s_ksks: Vec<ShoupRepr<M>>,
 }
 
 mod impl_shoup_non_interactive_server_key_eval_domain {
@@ -949,32 +2294,35 @@ mod impl_shoup_non_interactive_server_key_eval_domain {
     use super::*;
     use crate::{backend::Modulus, pbs::PbsKey};
 
-    impl<M: Matrix + ToShoup<Modulus = M::MatElement>, R, N>
-        From<NonInteractiveServerKeyEvaluationDomain<M, BoolParameters<M::MatElement>, R, N>>
-        for ShoupNonInteractiveServerKeyEvaluationDomain<M>
+    impl<M: Matrix> ShoupNonInteractiveServerKeyEvaluationDomain<M>
     where
         M::MatElement: FromPrimitive + ToPrimitive + PrimInt,
+        M: ToShoup<Modulus = M::MatElement>,
     {
-        fn from(
+        /// Like the `From` impl, but lets the caller pick whether the normal
+        /// matrices are retained (`ShoupMemoryMode::Full`) or dropped right
+        /// after their Shoup tables are derived (`ShoupMemoryMode::MinimalShoup`).
+        pub(crate) fn from_eval_domain<R, N>(
             value: NonInteractiveServerKeyEvaluationDomain<M, BoolParameters<M::MatElement>, R, N>,
+            mode: ShoupMemoryMode,
         ) -> Self {
             let rlwe_q = value.parameters.rlwe_q().q().unwrap();
 
             let rgsw_cts = value
                 .rgsw_cts
                 .into_iter()
-                .map(|m| NormalAndShoup::new_with_modulus(m, rlwe_q))
+                .map(|m| build_shoup_repr(m, rlwe_q, mode))
                 .collect_vec();
 
             let mut auto_keys = HashMap::new();
             value.auto_keys.into_iter().for_each(|(k, v)| {
-                auto_keys.insert(k, NormalAndShoup::new_with_modulus(v, rlwe_q));
+                auto_keys.insert(k, build_shoup_repr(v, rlwe_q, mode));
             });
 
             let ui_to_s_ksks = value
                 .ui_to_s_ksks
                 .into_iter()
-                .map(|m| NormalAndShoup::new_with_modulus(m, rlwe_q))
+                .map(|m| build_shoup_repr(m, rlwe_q, mode))
                 .collect_vec();
 
             Self {
@@ -986,10 +2334,23 @@ mod impl_shoup_non_interactive_server_key_eval_domain {
         }
     }
 
+    impl<M: Matrix + ToShoup<Modulus = M::MatElement>, R, N>
+        From<NonInteractiveServerKeyEvaluationDomain<M, BoolParameters<M::MatElement>, R, N>>
+        for ShoupNonInteractiveServerKeyEvaluationDomain<M>
+    where
+        M::MatElement: FromPrimitive + ToPrimitive + PrimInt,
+    {
+        fn from(
+            value: NonInteractiveServerKeyEvaluationDomain<M, BoolParameters<M::MatElement>, R, N>,
+        ) -> Self {
+            Self::from_eval_domain(value, ShoupMemoryMode::Full)
+        }
+    }
+
     impl<M: Matrix> PbsKey for ShoupNonInteractiveServerKeyEvaluationDomain<M> {
-        type AutoKey = NormalAndShoup<M>;
+        type AutoKey = ShoupRepr<M>;
         type LweKskKey = M;
-        type RgswCt = NormalAndShoup<M>;
+        type RgswCt = ShoupRepr<M>;
 
         fn galois_key_for_auto(&self, k: usize) -> &Self::AutoKey {
             self.auto_keys.get(&k).unwrap()
@@ -1007,10 +2368,10 @@ mod impl_shoup_non_interactive_server_key_eval_domain {
 /// Server key in evaluation domain with Shoup representations
 pub(crate) struct ShoupServerKeyEvaluationDomain<M> {
     /// Rgsw cts of LWE secret elements
-    rgsw_cts: Vec<NormalAndShoup<M>>,
+    rgsw_cts: Vec<ShoupRepr<M>>,
     /// Auto keys. Key corresponding to g^{k} is at index `k`. Key corresponding
     /// to -g is at 0
-    galois_keys: HashMap<usize, NormalAndShoup<M>>,
+    galois_keys: HashMap<usize, ShoupRepr<M>>,
     /// LWE ksk to key switching LWE ciphertext from RLWE secret to LWE secret
     lwe_ksk: M,
 }
@@ -1023,25 +2384,31 @@ mod shoup_server_key_eval_domain {
 
     use super::*;
 
-    impl<M: MatrixMut + MatrixEntity + ToShoup<Modulus = M::MatElement>, R, N>
-        From<ServerKeyEvaluationDomain<M, BoolParameters<M::MatElement>, R, N>>
-        for ShoupServerKeyEvaluationDomain<M>
+    impl<M: MatrixMut + MatrixEntity + ToShoup<Modulus = M::MatElement>> ShoupServerKeyEvaluationDomain<M>
     where
         <M as Matrix>::R: RowMut,
         M::MatElement: PrimInt + FromPrimitive,
     {
-        fn from(value: ServerKeyEvaluationDomain<M, BoolParameters<M::MatElement>, R, N>) -> Self {
+        /// Like the `From` impl, but lets the caller pick whether the normal
+        /// matrices are retained (`ShoupMemoryMode::Full`) or dropped right
+        /// after their Shoup tables are derived (`ShoupMemoryMode::MinimalShoup`),
+        /// trading the ability to read the key back out of evaluation domain
+        /// for roughly half the resident key size.
+        pub(crate) fn from_eval_domain<R, N>(
+            value: ServerKeyEvaluationDomain<M, BoolParameters<M::MatElement>, R, N>,
+            mode: ShoupMemoryMode,
+        ) -> Self {
             let q = value.parameters.rlwe_q().q().unwrap();
             // Rgsw ciphertexts
             let rgsw_cts = value
                 .rgsw_cts
                 .into_iter()
-                .map(|ct| NormalAndShoup::new_with_modulus(ct, q))
+                .map(|ct| build_shoup_repr(ct, q, mode))
                 .collect_vec();
 
             let mut auto_keys = HashMap::new();
             value.galois_keys.into_iter().for_each(|(index, key)| {
-                auto_keys.insert(index, NormalAndShoup::new_with_modulus(key, q));
+                auto_keys.insert(index, build_shoup_repr(key, q, mode));
             });
 
             Self {
@@ -1052,10 +2419,22 @@ mod shoup_server_key_eval_domain {
         }
     }
 
+    impl<M: MatrixMut + MatrixEntity + ToShoup<Modulus = M::MatElement>, R, N>
+        From<ServerKeyEvaluationDomain<M, BoolParameters<M::MatElement>, R, N>>
+        for ShoupServerKeyEvaluationDomain<M>
+    where
+        <M as Matrix>::R: RowMut,
+        M::MatElement: PrimInt + FromPrimitive,
+    {
+        fn from(value: ServerKeyEvaluationDomain<M, BoolParameters<M::MatElement>, R, N>) -> Self {
+            Self::from_eval_domain(value, ShoupMemoryMode::Full)
+        }
+    }
+
     impl<M: Matrix> PbsKey for ShoupServerKeyEvaluationDomain<M> {
-        type AutoKey = NormalAndShoup<M>;
+        type AutoKey = ShoupRepr<M>;
         type LweKskKey = M;
-        type RgswCt = NormalAndShoup<M>;
+        type RgswCt = ShoupRepr<M>;
 
         fn galois_key_for_auto(&self, k: usize) -> &Self::AutoKey {
             self.galois_keys.get(&k).unwrap()
@@ -1070,25 +2449,378 @@ mod shoup_server_key_eval_domain {
     }
 }
 
-/// Stores normal and shoup representation of Matrix elements (Normal, Shoup)
-pub(crate) struct NormalAndShoup<M>(M, M);
+/// A pluggable strategy for precomputing a fast auxiliary representation of
+/// fixed (key material) ring elements, so that multiplying by one of them
+/// at evaluation time skips a full modular reduction. `NormalAndPrecomputed`
+/// stores whatever `B` precomputes alongside the normal matrix; the two
+/// backends below trade memory for per-multiply cost differently.
+pub(crate) trait PrecomputedModMul<M: Matrix> {
+    type Precomputed;
+    fn precompute(value: &M, modulus: M::MatElement) -> Self::Precomputed;
+}
+
+/// Shoup's precomputed multiplier: a full shadow copy of `value`, one
+/// precomputed element per entry. Doubles memory but every multiply is a
+/// single word-sized mulhi plus a conditional subtraction.
+pub(crate) struct ShoupBackend;
+
+impl<M: ToShoup<Modulus = M::MatElement>> PrecomputedModMul<M> for ShoupBackend {
+    type Precomputed = M;
+    fn precompute(value: &M, modulus: M::MatElement) -> M {
+        M::to_shoup(value, modulus)
+    }
+}
+
+/// Barrett/fastdiv reduction: a single reciprocal of the modulus, shared by
+/// every element of `value`, instead of a whole shadow matrix. `a*b mod q`
+/// is then `a*b - floor((a*b * m') >> k) * q` followed by one conditional
+/// subtraction. `k` is the element's bit width (see `precompute`), so one
+/// subtraction suffices for products below `2^k`; that covers every product
+/// below `q^2` only when `q < 2^(k/2)`.
+pub(crate) struct BarrettBackend;
+
+impl<M: Matrix> PrecomputedModMul<M> for BarrettBackend
+where
+    M::MatElement: PrimInt + FromPrimitive + ToPrimitive,
+{
+    type Precomputed = M::MatElement;
+    fn precompute(_value: &M, modulus: M::MatElement) -> M::MatElement {
+        // `k` is the element's own bit width: doubling it would shift `1u128`
+        // out of range for any element width of 64 or more, and since `q` is
+        // always well below `2^k` the reciprocal `floor(2^k / q)` still fits
+        // back into that same width.
+        let k = (std::mem::size_of::<M::MatElement>() * 8) as u32;
+        let reciprocal = (1u128 << k) / modulus.to_u128().expect("modulus must fit in u128");
+        M::MatElement::from_u128(reciprocal)
+            .expect("reciprocal m' = floor(2^k / q) must fit back into the element width")
+    }
+}
 
-impl<M: ToShoup> NormalAndShoup<M> {
-    fn new_with_modulus(value: M, modulus: <M as ToShoup>::Modulus) -> Self {
-        let value_shoup = M::to_shoup(&value, modulus);
-        NormalAndShoup(value, value_shoup)
+/// Stores the normal representation of `value` alongside whatever `B`
+/// precomputes for it, generalizing the old Shoup-only `NormalAndShoup`.
+pub(crate) struct NormalAndPrecomputed<M, B: PrecomputedModMul<M>> {
+    value: M,
+    precomputed: B::Precomputed,
+}
+
+impl<M: Matrix, B: PrecomputedModMul<M>> NormalAndPrecomputed<M, B> {
+    fn new_with_modulus(value: M, modulus: M::MatElement) -> Self {
+        let precomputed = B::precompute(&value, modulus);
+        NormalAndPrecomputed { value, precomputed }
     }
 }
 
-impl<M> AsRef<M> for NormalAndShoup<M> {
+impl<M, B: PrecomputedModMul<M>> AsRef<M> for NormalAndPrecomputed<M, B> {
     fn as_ref(&self) -> &M {
-        &self.0
+        &self.value
+    }
+}
+
+/// Stores normal and shoup representation of Matrix elements (Normal, Shoup)
+pub(crate) type NormalAndShoup<M> = NormalAndPrecomputed<M, ShoupBackend>;
+
+impl<M: ToShoup<Modulus = M::MatElement>> WithShoupRepr for NormalAndPrecomputed<M, ShoupBackend> {
+    type M = M;
+    fn shoup_repr(&self) -> &Self::M {
+        &self.precomputed
+    }
+}
+
+/// Stores the normal representation alongside a single Barrett reciprocal,
+/// for parameter sets where halving evaluation-key RAM matters more than
+/// the extra per-multiply reduction work. Not yet wired into `PbsKey`: the
+/// blind-rotation inner loop (in the evaluator) currently only knows how to
+/// consume a `WithShoupRepr` key, so picking this backend per parameter set
+/// also needs that loop generalized over `PrecomputedModMul`, which is out
+/// of scope for this file.
+#[allow(dead_code)]
+pub(crate) type NormalAndBarrett<M> = NormalAndPrecomputed<M, BarrettBackend>;
+
+impl<M: Matrix> NormalAndPrecomputed<M, BarrettBackend>
+where
+    M::MatElement: PrimInt + FromPrimitive + ToPrimitive,
+{
+    #[allow(dead_code)]
+    pub(crate) fn barrett_reciprocal(&self) -> M::MatElement {
+        self.precomputed
     }
 }
 
-impl<M> WithShoupRepr for NormalAndShoup<M> {
+/// How much of the normal (non-Shoup) matrix a `Shoup*EvaluationDomain`
+/// keeps around once its Shoup table has been derived.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ShoupMemoryMode {
+    /// Keep both matrices, so the key can still be read back out of
+    /// evaluation domain (via `AsRef<M>`). This is what `From` uses.
+    Full,
+    /// Keep only the Shoup-precomputed matrix and drop the normal one right
+    /// after deriving it, roughly halving resident key size for deployments
+    /// that only ever call `shoup_repr()` on the hot path.
+    MinimalShoup,
+}
+
+/// Either a full `(normal, shoup)` pair or, in the `MinimalShoup` variant,
+/// just the Shoup table with the normal matrix already freed. Construct via
+/// [`build_shoup_repr`] so the mode decides at conversion time whether the
+/// normal matrix is ever kept.
+pub(crate) enum ShoupRepr<M> {
+    Full { normal: M, shoup: M },
+    MinimalShoup { shoup: M },
+}
+
+fn build_shoup_repr<M: ToShoup<Modulus = M::MatElement>>(
+    value: M,
+    modulus: M::MatElement,
+    mode: ShoupMemoryMode,
+) -> ShoupRepr<M> {
+    let shoup = M::to_shoup(&value, modulus);
+    match mode {
+        ShoupMemoryMode::Full => ShoupRepr::Full { normal: value, shoup },
+        // `value` is dropped here; its backing allocation is freed instead
+        // of being carried around as a second copy of the key.
+        ShoupMemoryMode::MinimalShoup => ShoupRepr::MinimalShoup { shoup },
+    }
+}
+
+impl<M> WithShoupRepr for ShoupRepr<M> {
     type M = M;
     fn shoup_repr(&self) -> &Self::M {
-        &self.1
+        match self {
+            ShoupRepr::Full { shoup, .. } => shoup,
+            ShoupRepr::MinimalShoup { shoup } => shoup,
+        }
+    }
+}
+
+impl<M> AsRef<M> for ShoupRepr<M> {
+    fn as_ref(&self) -> &M {
+        match self {
+            ShoupRepr::Full { normal, .. } => normal,
+            ShoupRepr::MinimalShoup { .. } => panic!(
+                "normal representation is unavailable: this key was converted with \
+                 ShoupMemoryMode::MinimalShoup, which drops it to save memory"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qualify_shares_separates_honest_from_mismatched() {
+        // Four parties: 0 and 1 commit honestly, 2 reveals something other
+        // than what it committed to, 3 agrees with 0/1 on cr_seed but not on
+        // parameters.
+        let revealed = [("honest-0", 7u64, 1u64), ("honest-1", 7, 1), ("liar", 7, 1), ("wrong-params", 7, 2)];
+        let commitments: Vec<ShareCommitment> = [
+            hash_bytes(b"honest-0"),
+            hash_bytes(b"honest-1"),
+            hash_bytes(b"something-else"),
+            hash_bytes(b"wrong-params"),
+        ]
+        .to_vec();
+
+        let (qualified, excluded) = qualify_shares(
+            &commitments,
+            &revealed,
+            |s| hash_bytes(s.0.as_bytes()),
+            |a, b| a.1 == b.1,
+            |a, b| a.2 == b.2,
+        );
+
+        assert_eq!(qualified, vec![0, 1]);
+        assert_eq!(excluded, vec![2, 3]);
+    }
+
+    #[test]
+    fn qualify_shares_is_not_fooled_by_a_malicious_party_at_index_zero() {
+        // Party 0 commits honestly but to garbage cr_seed/parameters that
+        // disagree with everyone else; parties 1-3 all agree with each
+        // other. Comparing against `revealed[0]` would wrongly exclude the
+        // honest majority -- the fix must qualify 1..=3 and exclude 0.
+        let revealed = [("malicious", 99u64, 99u64), ("honest-1", 7, 1), ("honest-2", 7, 1), ("honest-3", 7, 1)];
+        let commitments: Vec<ShareCommitment> =
+            revealed.iter().map(|s| hash_bytes(s.0.as_bytes())).collect();
+
+        let (qualified, excluded) = qualify_shares(
+            &commitments,
+            &revealed,
+            |s| hash_bytes(s.0.as_bytes()),
+            |a, b| a.1 == b.1,
+            |a, b| a.2 == b.2,
+        );
+
+        assert_eq!(qualified, vec![1, 2, 3]);
+        assert_eq!(excluded, vec![0]);
+    }
+
+    #[test]
+    fn share_proof_challenge_is_deterministic_and_round_separated() {
+        let cr_seed = [7u8; 32];
+        let part_b = [1u64, 2, 3, 4];
+        let t = [5u64, 6, 7, 8];
+
+        let c0 = share_proof_challenge(&cr_seed, &part_b, &t, 0);
+        let c0_again = share_proof_challenge(&cr_seed, &part_b, &t, 0);
+        assert_eq!(c0, c0_again, "same inputs must yield the same challenge");
+        assert!(c0 == 0 || c0 == 1);
+
+        // Changing only the round index must be able to flip the challenge
+        // -- i.e. rounds aren't accidentally sharing a transcript.
+        let challenges: Vec<u64> = (0..SHARE_PROOF_ROUNDS as usize)
+            .map(|round| share_proof_challenge(&cr_seed, &part_b, &t, round))
+            .collect();
+        assert!(
+            challenges.iter().any(|&c| c == 0) && challenges.iter().any(|&c| c == 1),
+            "40 independent rounds should not all collapse to the same bit"
+        );
+    }
+
+    #[test]
+    fn zero_share_round_leaves_the_ideal_secret_unchanged() {
+        let ring_size = 32;
+        let bound = 1u64 << 20;
+
+        // 3-party committee; every ordered pair shares a seed.
+        let seeds: HashMap<(usize, usize), [u8; 32]> = [(0, 1), (0, 2), (1, 2)]
+            .iter()
+            .map(|&(i, j)| ((i, j), [((i + 1) * 10 + j) as u8; 32]))
+            .collect();
+        let pairwise_seeds_for = |party: usize| -> HashMap<usize, [u8; 32]> {
+            (0..3)
+                .filter(|&other| other != party)
+                .map(|other| {
+                    let key = if party < other {
+                        (party, other)
+                    } else {
+                        (other, party)
+                    };
+                    (other, seeds[&key])
+                })
+                .collect()
+        };
+
+        let contributions: Vec<ZeroShareContribution> = (0..3)
+            .map(|party| {
+                generate_zero_share_contribution(party, ring_size, bound, &pairwise_seeds_for(party))
+            })
+            .collect();
+        assert_zero_share_round_is_zero(&contributions);
+
+        // The collective (additive) secret is the sum of every party's
+        // individual share; a proactive refresh must move each party's own
+        // share (the per-pair contributions are essentially never zero) but
+        // leave that sum -- the ideal secret -- unchanged.
+        let mut shares: Vec<RlweSecret> = (0..3).map(|_| RlweSecret::random(ring_size / 2, ring_size)).collect();
+        let sum_before: Vec<i64> = (0..ring_size)
+            .map(|i| shares.iter().map(|s| s.values()[i] as i64).sum())
+            .collect();
+
+        izip!(shares.iter_mut(), contributions.iter())
+            .for_each(|(share, c)| refresh_rlwe_secret_share(share, c));
+
+        let sum_after: Vec<i64> = (0..ring_size)
+            .map(|i| shares.iter().map(|s| s.values()[i] as i64).sum())
+            .collect();
+        assert_eq!(sum_before, sum_after);
+    }
+
+    #[test]
+    fn threshold_reconstruction_from_non_prefix_subset() {
+        // 5 parties, any 3 of them (threshold = 2) can reconstruct. Use the
+        // non-contiguous subset {1, 3, 5} -- the case that silently produced
+        // a wrong answer in release builds when Δ was scaled by the active
+        // subset's size (3!) instead of the total party count's (5!).
+        let parties = 5;
+        let threshold = 2;
+        let q = 1u64 << 40;
+        let active = [1usize, 3, 5];
+
+        let s = RlweSecret::random(4, 32);
+        let shares = shamir_share_rlwe_secret(&s, 4, threshold, parties);
+
+        for (coeff_idx, expected) in s.values().iter().enumerate() {
+            let partials: Vec<(usize, i64)> = shares
+                .iter()
+                .filter(|share| active.contains(&share.index()))
+                .map(|share| (share.index(), share.values()[coeff_idx]))
+                .collect();
+            assert_eq!(partials.len(), active.len());
+
+            let reconstructed = aggregate_threshold_decryption_shares(&partials, parties, q);
+            assert_eq!(reconstructed, *expected as i64);
+        }
+    }
+
+    #[test]
+    fn threshold_decryption_share_round_trips_an_rlwe_ciphertext() {
+        // End-to-end: Shamir-share an RLWE secret, encrypt a message under
+        // it, have every active party compute its partial decryption term
+        // via `gen_threshold_decryption_share`, aggregate those terms, and
+        // check the plaintext comes back -- not just that raw secret shares
+        // Lagrange-interpolate in isolation. Ring size/modulus are kept
+        // small (rather than production-scale) so the exact, unreduced
+        // integer arithmetic `gen_threshold_decryption_share`/
+        // `aggregate_threshold_decryption_shares` both rely on can't
+        // overflow `i64` -- see the doc comment on `gen_threshold_decryption_share`.
+        let ring_size = 8;
+        let q = 1u64 << 16;
+        let parties = 3;
+        let threshold = 1;
+        let active = [1usize, 3];
+
+        let s = RlweSecret::random(ring_size / 2, ring_size);
+        let shares = shamir_share_rlwe_secret(&s, ring_size / 2, threshold, parties);
+
+        // Negacyclic convolution with the same `x^n = -1` sign convention as
+        // `gen_threshold_decryption_share`, used here only to build a
+        // ciphertext to decrypt.
+        let negacyclic_conv = |a: &[i64], b: &[i64]| -> Vec<i64> {
+            (0..ring_size)
+                .map(|k| {
+                    (0..ring_size)
+                        .map(|j| {
+                            let b_kj = b[(k + ring_size - j) % ring_size];
+                            if j <= k { a[j] * b_kj } else { -(a[j] * b_kj) }
+                        })
+                        .sum()
+                })
+                .collect()
+        };
+
+        // Manually build an RLWE encryption of `m` under the ideal secret
+        // `s`, with zero encryption noise so the recovered value can be
+        // checked for exact equality: `b = m + s*a`.
+        let m: Vec<i64> = (0..ring_size as i64).collect();
+        let mut rng = DefaultSecureRng::new();
+        let mut a = vec![0u64; ring_size];
+        RandomUniformDist::<[u64]>::random_fill(&mut rng, &q, a.as_mut_slice());
+        let a_i64: Vec<i64> = a.iter().map(|&v| v as i64).collect();
+
+        let s_i64: Vec<i64> = s.values().iter().map(|&v| v as i64).collect();
+        let sa = negacyclic_conv(&s_i64, &a_i64);
+        let b: Vec<u64> = izip!(sa.iter(), m.iter()).map(|(&sa_k, &m_k)| (sa_k + m_k).rem_euclid(q as i64) as u64).collect();
+
+        // Smudging bound of 1 forces the sampled noise to be exactly 0 (the
+        // only value in `[0, 1)`), so the aggregated partials must
+        // reconstruct `m` exactly.
+        let per_party_shares: Vec<(usize, Vec<i64>)> = shares
+            .iter()
+            .filter(|share| active.contains(&share.index()))
+            .map(|share| (share.index(), gen_threshold_decryption_share(&a, share, 1)))
+            .collect();
+
+        let recovered: Vec<i64> = (0..ring_size)
+            .map(|coeff_idx| {
+                let partials: Vec<(usize, i64)> =
+                    per_party_shares.iter().map(|(idx, vals)| (*idx, vals[coeff_idx])).collect();
+                let neg_sa_term = aggregate_threshold_decryption_shares(&partials, parties, q);
+                (neg_sa_term + b[coeff_idx] as i64).rem_euclid(q as i64)
+            })
+            .collect();
+
+        assert_eq!(recovered, m);
     }
 }
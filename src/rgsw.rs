@@ -6,17 +6,299 @@ use std::{
 };
 
 use itertools::{izip, Itertools};
-use num_traits::{PrimInt, Signed, ToPrimitive, Zero};
+use num_traits::{FromPrimitive, PrimInt, Signed, ToPrimitive, Zero};
 
 use crate::{
-    backend::{ArithmeticOps, VectorOps},
+    backend::{ArithmeticOps, ShoupVectorOps, VectorOps},
     decomposer::{self, Decomposer},
-    ntt::{self, Ntt, NttInit},
+    ntt::{self, Ntt, NttBackendU64, NttInit},
     random::{DefaultSecureRng, NewWithSeed, RandomGaussianDist, RandomUniformDist},
-    utils::{fill_random_ternary_secret_with_hamming_weight, TryConvertFrom, WithLocal},
+    utils::{
+        fill_random_ternary_secret_with_hamming_weight, generate_prime, ToShoup, TryConvertFrom,
+        WithLocal,
+    },
     Matrix, MatrixEntity, MatrixMut, Row, RowEntity, RowMut, Secret,
 };
 
+/// Distribution from which an encryption routine draws its secret (or
+/// ephemeral public-key secret) polynomial.
+pub(crate) enum SecretDistribution {
+    /// Ternary coefficients `{-1, 0, 1}` with the given Hamming weight.
+    Ternary { hamming_weight: usize },
+    /// Coefficients drawn uniformly from `[0, q)`.
+    Uniform,
+}
+
+impl SecretDistribution {
+    /// Samples `out` according to `self`. `out` is assumed to be in
+    /// coefficient domain and its length is the ring size.
+    pub(crate) fn sample<E, R>(&self, out: &mut [E], q: &E, rng: &mut R)
+    where
+        E: Copy + Zero + Signed,
+        R: RandomUniformDist<[E], Parameters = E>,
+    {
+        match self {
+            SecretDistribution::Ternary { hamming_weight } => {
+                fill_random_ternary_secret_with_hamming_weight(out, *hamming_weight, rng)
+            }
+            SecretDistribution::Uniform => RandomUniformDist::random_fill(rng, q, out),
+        }
+    }
+}
+
+/// Distribution from which an encryption routine draws its error
+/// polynomial.
+pub(crate) enum ErrorDistribution {
+    /// The crate's usual discrete Gaussian error, sampled via
+    /// [`RandomGaussianDist`].
+    Gaussian,
+    /// Centered binomial error with parameter `eta`: each coefficient is
+    /// `sum_{j<eta} a_j - sum_{j<eta} b_j` for independent uniform bits
+    /// `a_j, b_j`.
+    CenteredBinomial { eta: usize },
+}
+
+impl ErrorDistribution {
+    /// Samples `out` according to `self`. `out` is assumed to be in
+    /// coefficient domain and its length is the ring size.
+    pub(crate) fn sample<E, R, ModOp>(&self, out: &mut [E], q: &E, mod_op: &ModOp, rng: &mut R)
+    where
+        E: Copy + Zero + FromPrimitive,
+        R: RandomGaussianDist<[E], Parameters = E> + RandomUniformDist<[E], Parameters = E>,
+        ModOp: VectorOps<Element = E>,
+    {
+        match self {
+            ErrorDistribution::Gaussian => RandomGaussianDist::random_fill(rng, q, out),
+            ErrorDistribution::CenteredBinomial { eta } => {
+                out.iter_mut().for_each(|o| *o = E::zero());
+                let two = E::from_u64(2).unwrap();
+                let mut bit = vec![E::zero(); out.len()];
+                for _ in 0..*eta {
+                    RandomUniformDist::random_fill(rng, &two, &mut bit);
+                    mod_op.elwise_add_mut(out, &bit);
+                    RandomUniformDist::random_fill(rng, &two, &mut bit);
+                    mod_op.elwise_sub_mut(out, &bit);
+                }
+            }
+        }
+    }
+}
+
+// Little-endian, fixed-width (8 byte) element encoding shared by the
+// `to_bytes`/`from_bytes` impls of the `Seeded*` types below. Elements are
+// serialized as u64 regardless of `M::MatElement`'s native width, which is
+// sufficient for every modulus this crate currently supports.
+fn write_element<E: ToPrimitive>(out: &mut Vec<u8>, el: E) {
+    out.extend_from_slice(&el.to_u64().unwrap().to_le_bytes());
+}
+
+fn read_element<E: FromPrimitive>(bytes: &[u8], at: &mut usize) -> E {
+    let v = u64::from_le_bytes(bytes[*at..*at + 8].try_into().unwrap());
+    *at += 8;
+    E::from_u64(v).unwrap()
+}
+
+fn write_len(out: &mut Vec<u8>, len: usize) {
+    out.extend_from_slice(&(len as u64).to_le_bytes());
+}
+
+fn read_len(bytes: &[u8], at: &mut usize) -> usize {
+    let v = u64::from_le_bytes(bytes[*at..*at + 8].try_into().unwrap());
+    *at += 8;
+    v as usize
+}
+
+fn read_seed(bytes: &[u8], at: &mut usize) -> [u8; 32] {
+    let seed: [u8; 32] = bytes[*at..*at + 32].try_into().unwrap();
+    *at += 32;
+    seed
+}
+
+fn write_row<E: ToPrimitive + Copy>(out: &mut Vec<u8>, row: &[E]) {
+    row.iter().for_each(|el| write_element(out, *el));
+}
+
+fn read_row<E: FromPrimitive>(bytes: &[u8], at: &mut usize, row: &mut [E]) {
+    row.iter_mut().for_each(|el| *el = read_element(bytes, at));
+}
+
+/// Derives a deterministic per-row sub-seed from a base seed by XORing the
+/// row index into its leading bytes. This lets a single seeded stream of
+/// public randomness (e.g. the `a` polynomials of RLWE'(m)) be split across
+/// `parallel`-feature worker threads as independent per-row draws. Row 0's
+/// sub-seed is the base seed itself; every other row's values differ from
+/// what a sequential draw from the base seed produces for that row.
+fn sub_seed(seed: &[u8; 32], row: usize) -> [u8; 32] {
+    let mut out = *seed;
+    let row_bytes = (row as u64).to_le_bytes();
+    izip!(out.iter_mut(), row_bytes.iter()).for_each(|(o, r)| *o ^= r);
+    out
+}
+
+/// Searches for a chain of `count` distinct NTT-friendly primes suitable for
+/// an RNS basis (see [`RnsModulus::for_ring`]): repeatedly calls
+/// `generate_prime` with a shrinking upper bound so each prime found is
+/// strictly smaller than the last. Distinct primes are automatically
+/// pairwise coprime, which is all an RNS basis needs -- no extra
+/// coprimality check is required on top of `generate_prime`'s own
+/// `q ≡ 1 mod 2 * ring_size` (NTT-friendliness) guarantee.
+///
+/// Note: this only assembles the chain: it still searches for primality via
+/// whatever `generate_prime` (in the `utils` module, not present in this
+/// tree snapshot) does internally. Swapping that search's primality test for
+/// a Baillie-PSW test is out of reach here.
+pub(crate) fn generate_prime_chain(logq: usize, ring_size: u64, count: usize) -> Vec<u64> {
+    let mut primes = Vec::with_capacity(count);
+    let mut upper_bound = 1u64 << logq;
+    while primes.len() < count {
+        let p = generate_prime(logq, 2 * ring_size, upper_bound)
+            .expect("ran out of NTT-friendly primes below the requested bit size");
+        primes.push(p);
+        upper_bound = p;
+    }
+    primes
+}
+
+/// A product modulus q = p_0 * p_1 * ... * p_{L-1} of NTT-friendly,
+/// word-sized primes, carried as its prime basis plus the CRT constants
+/// needed to reconstruct a value from its residues.
+///
+/// This only covers the cross-prime-independent part of RNS: basis storage,
+/// CRT reconstruction, and base conversion. Wiring `routine`, `rlwe_by_rgsw`
+/// and `galois_auto` to iterate limbs independently, and giving each prime
+/// its own `Ntt`/`Decomposer` instance, requires per-limb plumbing in the
+/// backend/ntt/decomposer modules that this chunk does not contain.
+pub(crate) struct RnsModulus {
+    primes: Vec<u64>,
+    /// q / p_i for each limb i.
+    q_hat: Vec<u128>,
+    /// (q_hat_i)^{-1} mod p_i for each limb i.
+    q_hat_inv_mod_p: Vec<u64>,
+}
+
+impl RnsModulus {
+    pub(crate) fn new(primes: Vec<u64>) -> Self {
+        let q: u128 = primes.iter().map(|&p| p as u128).product();
+        let q_hat: Vec<u128> = primes.iter().map(|&p| q / p as u128).collect();
+        let q_hat_inv_mod_p = izip!(primes.iter(), q_hat.iter())
+            .map(|(&p, &qh)| mod_inverse((qh % p as u128) as u64, p))
+            .collect();
+
+        RnsModulus {
+            primes,
+            q_hat,
+            q_hat_inv_mod_p,
+        }
+    }
+
+    pub(crate) fn primes(&self) -> &[u64] {
+        &self.primes
+    }
+
+    /// Builds an `RnsModulus` basis of `limbs` distinct, NTT-friendly primes
+    /// of bit size `logq_per_limb`, via [`generate_prime_chain`].
+    pub(crate) fn for_ring(logq_per_limb: usize, ring_size: u64, limbs: usize) -> Self {
+        Self::new(generate_prime_chain(logq_per_limb, ring_size, limbs))
+    }
+
+    /// CRT reconstruction: given residues `[x mod p_0, ..., x mod p_{L-1}]`,
+    /// recovers `x mod q`.
+    pub(crate) fn reconstruct(&self, residues: &[u64]) -> u128 {
+        let q: u128 = self.primes.iter().map(|&p| p as u128).product();
+        izip!(
+            self.primes.iter(),
+            self.q_hat.iter(),
+            self.q_hat_inv_mod_p.iter(),
+            residues.iter()
+        )
+        .fold(0u128, |acc, (&p, &q_hat_i, &q_hat_inv_i, &x_i)| {
+            let term = (x_i as u128 * q_hat_inv_i as u128) % p as u128;
+            (acc + term * q_hat_i) % q
+        })
+    }
+
+    /// Base conversion: re-expresses a value given by its residues in this
+    /// basis as its residue modulo a prime outside the basis.
+    pub(crate) fn convert_to(&self, residues: &[u64], p_new: u64) -> u64 {
+        (self.reconstruct(residues) % p_new as u128) as u64
+    }
+
+    /// Per-prime gadget vector for RNS decomposition: each limb `p_i` gets
+    /// its own base-`2^log_b` digit decomposition so that a decomposed
+    /// digit never has to straddle a prime boundary. This is the piece of
+    /// an RNS gadget that belongs with the ring arithmetic in this file;
+    /// dispatching `VectorOps`/`Ntt` component-wise across limbs (one NTT
+    /// table per prime) so that `secret_key_encrypt_rgsw`, `rlwe_ksk_gen`,
+    /// and `rgsw_by_rgsw_inplace` can run unmodified over an RNS element
+    /// type is backend/NTT-table plumbing that lives outside this file.
+    pub(crate) fn gadget_vector_per_prime(&self, log_b: usize) -> Vec<Vec<u64>> {
+        self.primes
+            .iter()
+            .map(|&p| {
+                let bit_len = 64 - p.leading_zeros() as usize;
+                let d = (bit_len + log_b - 1) / log_b;
+                (0..d)
+                    .map(|i| {
+                        let shift = i * log_b;
+                        if shift >= 64 {
+                            0
+                        } else {
+                            1u64 << shift
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Reconstructs `x mod q` from `residues` and lifts it to its
+    /// signed-balanced representative in `(-q/2, q/2]`. Used by
+    /// `measure_noise`-style callers that need a centered, not unsigned,
+    /// view of a decrypted RNS coefficient.
+    pub(crate) fn signed_balanced_lift(&self, residues: &[u64]) -> i128 {
+        let q = self.primes.iter().map(|&p| p as u128).product::<u128>();
+        let x = self.reconstruct(residues);
+        if x > q / 2 {
+            x as i128 - q as i128
+        } else {
+            x as i128
+        }
+    }
+
+    /// Drops the last limb of the basis, returning the smaller `RnsModulus`
+    /// together with the residues of the *same* integer `x` re-expressed in
+    /// it, i.e. `x mod (q / p_{L-1})` per remaining limb. This is pure CRT
+    /// base conversion -- `x` itself is unchanged, only the basis it's
+    /// expressed in shrinks -- not a modulus-switch: it doesn't rescale `x`
+    /// by `1/p_{L-1}` and round, so it does nothing to reduce noise the way
+    /// an actual `Q -> Q/p` switch of a ciphertext coefficient would. A
+    /// caller after noise-reducing mod-switch behavior needs to rescale and
+    /// round `x` itself before calling this, or use a different helper.
+    pub(crate) fn drop_last_prime(&self, residues: &[u64]) -> (RnsModulus, Vec<u64>) {
+        assert!(self.primes.len() > 1);
+        let smaller = RnsModulus::new(self.primes[..self.primes.len() - 1].to_vec());
+        let new_residues = smaller
+            .primes
+            .iter()
+            .map(|&p| self.convert_to(residues, p))
+            .collect();
+        (smaller, new_residues)
+    }
+}
+
+/// Extended-Euclid modular inverse of `a` mod `m`. Used by `RnsModulus` to
+/// derive the CRT constant `(q/p_i)^{-1} mod p_i` for each limb.
+fn mod_inverse(a: u64, m: u64) -> u64 {
+    let (mut old_r, mut r) = (a as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+    (old_s.rem_euclid(m as i128)) as u64
+}
+
 pub struct SeededAutoKey<M, S>
 where
     M: Matrix,
@@ -46,13 +328,64 @@ impl<M: Matrix + MatrixEntity, S> SeededAutoKey<M, S> {
     }
 }
 
+impl<M: Matrix + MatrixEntity> SeededAutoKey<M, [u8; 32]>
+where
+    M::R: RowMut,
+    M::MatElement: Copy + ToPrimitive + FromPrimitive,
+{
+    /// Serializes to seed || modulus || (d, ring_size) || data, i.e. exactly
+    /// the part of `SeededAutoKey` that cannot be regenerated from the seed
+    /// (RLWE'_A(-s(X^k)) is re-sampled by `AutoKeyEvaluationDomain::from`).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (d, ring_size) = self.data.dimension();
+        let mut out = Vec::with_capacity(32 + 8 + 8 + 8 + d * ring_size * 8);
+        out.extend_from_slice(&self.seed);
+        write_element(&mut out, self.modulus);
+        write_len(&mut out, d);
+        write_len(&mut out, ring_size);
+        self.data
+            .iter_rows()
+            .for_each(|r| write_row(&mut out, r.as_ref()));
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut at = 0;
+        let seed = read_seed(bytes, &mut at);
+        let modulus = read_element(bytes, &mut at);
+        let d = read_len(bytes, &mut at);
+        let ring_size = read_len(bytes, &mut at);
+
+        let mut data = M::zeros(d, ring_size);
+        data.iter_rows_mut()
+            .for_each(|r| read_row(bytes, &mut at, r.as_mut()));
+
+        SeededAutoKey {
+            data,
+            seed,
+            modulus,
+        }
+    }
+}
+
 pub struct AutoKeyEvaluationDomain<M, R, N> {
     data: M,
+    /// Shoup precomputed multipliers for `data`, used by `routine_shoup` to
+    /// avoid a full wide reduction when this (fixed) key material is the
+    /// multiplier operand of `elwise_fma_shoup_mut`. Same dimension/layout as
+    /// `data`.
+    shoup_data: M,
     _phantom: PhantomData<(R, N)>,
 }
 
+impl<M, R, N> AutoKeyEvaluationDomain<M, R, N> {
+    pub(crate) fn shoup_data(&self) -> &M {
+        &self.shoup_data
+    }
+}
+
 impl<
-        M: MatrixMut + MatrixEntity,
+        M: MatrixMut + MatrixEntity + ToShoup<Modulus = M::MatElement>,
         R: RandomUniformDist<[M::MatElement], Parameters = M::MatElement> + NewWithSeed,
         N: NttInit<Element = M::MatElement> + Ntt<Element = M::MatElement>,
     > From<&SeededAutoKey<M, R::Seed>> for AutoKeyEvaluationDomain<M, R, N>
@@ -81,8 +414,13 @@ where
         data.iter_rows_mut()
             .for_each(|r| ntt_op.forward(r.as_mut()));
 
+        // -s(X^k) is fixed key material on every key-switch it takes part in, so
+        // precompute its Shoup multipliers once here rather than per `routine` call.
+        let shoup_data = M::to_shoup(&data, value.modulus);
+
         AutoKeyEvaluationDomain {
             data,
+            shoup_data,
             _phantom: PhantomData,
         }
     }
@@ -127,6 +465,46 @@ impl<M: Matrix + MatrixEntity, S> SeededRgswCiphertext<M, S> {
     }
 }
 
+impl<M: Matrix + MatrixEntity> SeededRgswCiphertext<M, [u8; 32]>
+where
+    M::R: RowMut,
+    M::MatElement: Copy + ToPrimitive + FromPrimitive,
+{
+    /// Serializes to seed || modulus || (d*3, ring_size) || data, i.e. the
+    /// `[RLWE'_A(-sm) || RLWE'_B(-sm) || RLWE'_B(m)]` rows; RLWE'_A(m) is
+    /// regenerated from the seed by `RgswCiphertextEvaluationDomain::from`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (d3, ring_size) = self.data.dimension();
+        let mut out = Vec::with_capacity(32 + 8 + 8 + 8 + d3 * ring_size * 8);
+        out.extend_from_slice(&self.seed);
+        write_element(&mut out, self.modulus);
+        write_len(&mut out, d3);
+        write_len(&mut out, ring_size);
+        self.data
+            .iter_rows()
+            .for_each(|r| write_row(&mut out, r.as_ref()));
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut at = 0;
+        let seed = read_seed(bytes, &mut at);
+        let modulus = read_element(bytes, &mut at);
+        let d3 = read_len(bytes, &mut at);
+        let ring_size = read_len(bytes, &mut at);
+
+        let mut data = M::zeros(d3, ring_size);
+        data.iter_rows_mut()
+            .for_each(|r| read_row(bytes, &mut at, r.as_mut()));
+
+        SeededRgswCiphertext {
+            data,
+            seed,
+            modulus,
+        }
+    }
+}
+
 impl<M: Debug + Matrix, S: Debug> Debug for SeededRgswCiphertext<M, S>
 where
     M::MatElement: Debug,
@@ -142,11 +520,22 @@ where
 
 pub struct RgswCiphertextEvaluationDomain<M, R, N> {
     pub(crate) data: M,
+    /// Shoup precomputed multipliers for `data`. RGSW key material (RLWE'(-sm),
+    /// RLWE'(m)) is always the fixed operand in the external-product `routine`
+    /// calls in `rlwe_by_rgsw`/`less1_rlwe_by_rgsw`, so its Shoup form is
+    /// computed once here instead of on every product.
+    pub(crate) shoup_data: M,
     _phantom: PhantomData<(R, N)>,
 }
 
+impl<M, R, N> RgswCiphertextEvaluationDomain<M, R, N> {
+    pub(crate) fn shoup_data(&self) -> &M {
+        &self.shoup_data
+    }
+}
+
 impl<
-        M: MatrixMut + MatrixEntity,
+        M: MatrixMut + MatrixEntity + ToShoup<Modulus = M::MatElement>,
         R: NewWithSeed + RandomUniformDist<[M::MatElement], Parameters = M::MatElement>,
         N: NttInit<Element = M::MatElement> + Ntt<Element = M::MatElement> + Debug,
     > From<&SeededRgswCiphertext<M, R::Seed>> for RgswCiphertextEvaluationDomain<M, R, N>
@@ -190,15 +579,18 @@ where
         data.iter_rows_mut()
             .for_each(|ri| nttop.forward(ri.as_mut()));
 
+        let shoup_data = M::to_shoup(&data, value.modulus);
+
         Self {
             data: data,
+            shoup_data,
             _phantom: PhantomData,
         }
     }
 }
 
 impl<
-        M: MatrixMut + MatrixEntity,
+        M: MatrixMut + MatrixEntity + ToShoup<Modulus = M::MatElement>,
         R,
         N: NttInit<Element = M::MatElement> + Ntt<Element = M::MatElement>,
     > From<&RgswCiphertext<M>> for RgswCiphertextEvaluationDomain<M, R, N>
@@ -235,8 +627,11 @@ where
         data.iter_rows_mut()
             .for_each(|ri| nttop.forward(ri.as_mut()));
 
+        let shoup_data = M::to_shoup(&data, value.modulus);
+
         Self {
             data: data,
+            shoup_data,
             _phantom: PhantomData,
         }
     }
@@ -285,6 +680,39 @@ impl<R: RowEntity, S> SeededRlweCiphertext<R, S> {
     }
 }
 
+impl<R: RowEntity + RowMut> SeededRlweCiphertext<R, [u8; 32]>
+where
+    R::Element: Copy + ToPrimitive + FromPrimitive,
+{
+    /// Serializes to seed || modulus || ring_size || data (the `b` row; `a`
+    /// is regenerated from the seed by `RlweCiphertext::from`).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let ring_size = self.data.as_ref().len();
+        let mut out = Vec::with_capacity(32 + 8 + 8 + ring_size * 8);
+        out.extend_from_slice(&self.seed);
+        write_element(&mut out, self.modulus);
+        write_len(&mut out, ring_size);
+        write_row(&mut out, self.data.as_ref());
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut at = 0;
+        let seed = read_seed(bytes, &mut at);
+        let modulus = read_element(bytes, &mut at);
+        let ring_size = read_len(bytes, &mut at);
+
+        let mut data = R::zeros(ring_size);
+        read_row(bytes, &mut at, data.as_mut());
+
+        SeededRlweCiphertext {
+            data,
+            seed,
+            modulus,
+        }
+    }
+}
+
 pub struct RlweCiphertext<M, Rng> {
     pub(crate) data: M,
     pub(crate) is_trivial: bool,
@@ -382,6 +810,39 @@ impl<Ro: RowEntity, S> SeededRlwePublicKey<Ro, S> {
     }
 }
 
+impl<Ro: RowEntity + RowMut> SeededRlwePublicKey<Ro, [u8; 32]>
+where
+    Ro::Element: Copy + ToPrimitive + FromPrimitive,
+{
+    /// Serializes to seed || modulus || ring_size || data (the `b` row; `a`
+    /// is regenerated from the seed by `RlwePublicKey::from`).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let ring_size = self.data.as_ref().len();
+        let mut out = Vec::with_capacity(32 + 8 + 8 + ring_size * 8);
+        out.extend_from_slice(&self.seed);
+        write_element(&mut out, self.modulus);
+        write_len(&mut out, ring_size);
+        write_row(&mut out, self.data.as_ref());
+        out
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut at = 0;
+        let seed = read_seed(bytes, &mut at);
+        let modulus = read_element(bytes, &mut at);
+        let ring_size = read_len(bytes, &mut at);
+
+        let mut data = Ro::zeros(ring_size);
+        read_row(bytes, &mut at, data.as_mut());
+
+        Self {
+            data,
+            seed,
+            modulus,
+        }
+    }
+}
+
 pub struct RlwePublicKey<M, R> {
     data: M,
     _phantom: PhantomData<R>,
@@ -474,6 +935,375 @@ pub(crate) fn routine<R: RowMut, ModOp: VectorOps<Element = R::Element>>(
     });
 }
 
+/// Same as `routine`, but `matrix_b` is fixed key material whose Shoup
+/// multipliers (`matrix_b_shoup`) were precomputed once by the caller (see
+/// `AutoKeyEvaluationDomain`/`RgswCiphertextEvaluationDomain`). Each
+/// multiply-add then avoids a full wide reduction, computing the estimated
+/// quotient from the precomputed multiplier instead.
+pub(crate) fn routine_shoup<R: RowMut, ModOp: ShoupVectorOps<Element = R::Element>>(
+    write_to_row: &mut [R::Element],
+    matrix_a: &[R],
+    matrix_b: &[R],
+    matrix_b_shoup: &[R],
+    mod_op: &ModOp,
+) {
+    izip!(matrix_a.iter(), matrix_b.iter(), matrix_b_shoup.iter()).for_each(|(a, b, b_shoup)| {
+        mod_op.elwise_fma_shoup_mut(write_to_row, a.as_ref(), b.as_ref(), b_shoup.as_ref());
+    });
+}
+
+/// Number of lanes [`simd_for_each`] groups loop iterations into. This tree
+/// has no `backend.rs`/`ntt.rs` (and no `Cargo.toml` to gate an
+/// architecture-specific dependency like `wide` behind), so there's nowhere
+/// to host a real `std::arch`-dispatched AVX2/AVX-512 kernel; this is the
+/// portable, intrinsics-free stand-in -- processing `SIMD_LANES` independent
+/// elements per outer-loop step so the optimizer has the chance to pack them
+/// into actual SIMD registers on its own.
+#[cfg(feature = "simd")]
+const SIMD_LANES: usize = 4;
+
+/// Calls `body(i)` once for every `i in 0..len`, `SIMD_LANES` indices at a
+/// time (with a scalar tail for a length that isn't a multiple of
+/// `SIMD_LANES`). See [`SIMD_LANES`] for why this -- rather than a real
+/// `target_feature = "avx2"` kernel -- is what this tree can host.
+#[cfg(feature = "simd")]
+fn simd_for_each(len: usize, mut body: impl FnMut(usize)) {
+    let full_chunks = len / SIMD_LANES;
+    for chunk in 0..full_chunks {
+        let base = chunk * SIMD_LANES;
+        for lane in 0..SIMD_LANES {
+            body(base + lane);
+        }
+    }
+    for i in (full_chunks * SIMD_LANES)..len {
+        body(i);
+    }
+}
+
+/// Lane-chunked (see [`simd_for_each`]) `ArithmeticOps`/`VectorOps`/
+/// `ShoupVectorOps` backend for a `u64` modulus, gated behind the `simd`
+/// feature so the pure-Rust scalar backend (`ModularOpsU64`, in `backend.rs`)
+/// remains the default. Implements the same trait surface as
+/// `ModularOpsU64` so callers of `routine`/`routine_shoup` are unchanged.
+#[cfg(feature = "simd")]
+pub(crate) struct SimdModularOpsU64 {
+    modulus: u64,
+}
+
+#[cfg(feature = "simd")]
+impl ModInit for SimdModularOpsU64 {
+    type Element = u64;
+
+    fn new(modulus: u64) -> Self {
+        Self { modulus }
+    }
+}
+
+#[cfg(feature = "simd")]
+impl ArithmeticOps for SimdModularOpsU64 {
+    type Element = u64;
+
+    fn neg(&self, a: &u64) -> u64 {
+        if *a == 0 {
+            0
+        } else {
+            self.modulus - a
+        }
+    }
+
+    fn modulus(&self) -> u64 {
+        self.modulus
+    }
+}
+
+#[cfg(feature = "simd")]
+impl VectorOps for SimdModularOpsU64 {
+    type Element = u64;
+
+    fn elwise_add_mut(&self, a: &mut [u64], b: &[u64]) {
+        let q = self.modulus;
+        simd_for_each(a.len(), |i| {
+            let sum = a[i] + b[i];
+            a[i] = if sum >= q { sum - q } else { sum };
+        });
+    }
+
+    fn elwise_sub_mut(&self, a: &mut [u64], b: &[u64]) {
+        let q = self.modulus;
+        simd_for_each(a.len(), |i| {
+            a[i] = if a[i] >= b[i] {
+                a[i] - b[i]
+            } else {
+                a[i] + q - b[i]
+            };
+        });
+    }
+
+    fn elwise_neg_mut(&self, a: &mut [u64]) {
+        let q = self.modulus;
+        simd_for_each(a.len(), |i| {
+            a[i] = if a[i] == 0 { 0 } else { q - a[i] };
+        });
+    }
+
+    fn elwise_mul_mut(&self, a: &mut [u64], b: &[u64]) {
+        let q = self.modulus as u128;
+        simd_for_each(a.len(), |i| {
+            a[i] = ((a[i] as u128 * b[i] as u128) % q) as u64;
+        });
+    }
+
+    fn elwise_scalar_mul(&self, out: &mut [u64], a: &[u64], b: &u64) {
+        let q = self.modulus as u128;
+        let scalar = *b as u128;
+        simd_for_each(out.len(), |i| {
+            out[i] = ((a[i] as u128 * scalar) % q) as u64;
+        });
+    }
+
+    fn elwise_fma_mut(&self, out: &mut [u64], a: &[u64], b: &[u64]) {
+        let q = self.modulus as u128;
+        simd_for_each(out.len(), |i| {
+            let product = (a[i] as u128 * b[i] as u128) % q;
+            out[i] = ((out[i] as u128 + product) % q) as u64;
+        });
+    }
+}
+
+/// Same Shoup-multiplier trick `routine_shoup` relies on (see
+/// `chunk0-1`): `t` is the estimated quotient from the precomputed
+/// multiplier, and a single conditional subtraction finishes the reduction.
+#[cfg(feature = "simd")]
+impl ShoupVectorOps for SimdModularOpsU64 {
+    type Element = u64;
+
+    fn elwise_fma_shoup_mut(&self, out: &mut [u64], a: &[u64], b: &[u64], b_shoup: &[u64]) {
+        let q = self.modulus;
+        simd_for_each(out.len(), |i| {
+            let t = (((a[i] as u128) * (b_shoup[i] as u128)) >> 64) as u64;
+            let mut r = a[i].wrapping_mul(b[i]).wrapping_sub(t.wrapping_mul(q));
+            if r >= q {
+                r -= q;
+            }
+            let sum = out[i] + r;
+            out[i] = if sum >= q { sum - q } else { sum };
+        });
+    }
+}
+
+/// `base^exp mod modulus`, via square-and-multiply.
+#[cfg(feature = "simd")]
+fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1u128;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = (result * base) % modulus;
+        }
+        base = (base * base) % modulus;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Finds a primitive `order`-th root of unity mod `modulus`, where `order`
+/// is a power of two and `modulus` is prime with `(modulus - 1) % order ==
+/// 0` -- exactly the NTT-friendliness condition `generate_prime` already
+/// guarantees elsewhere in this file. For a power-of-two `order`, `psi` has
+/// exact order `order` iff `psi^(order/2) == modulus - 1`: `Z_modulus^*` is
+/// cyclic of even order, so its only element of multiplicative order two is
+/// `-1`, and any `psi` with `psi^order == 1` but `psi^(order/2) != 1` must
+/// have `psi^(order/2)` equal to that unique order-two element.
+#[cfg(feature = "simd")]
+fn find_primitive_root(modulus: u64, order: u64) -> u64 {
+    let exponent = (modulus - 1) / order;
+    let mut candidate = 2u64;
+    loop {
+        let psi = mod_pow(candidate, exponent, modulus);
+        if psi != 0 && mod_pow(psi, order / 2, modulus) == modulus - 1 {
+            return psi;
+        }
+        candidate += 1;
+    }
+}
+
+/// Direct (schoolbook, O(ring_size^2)) negacyclic NTT: forward evaluates the
+/// input polynomial at the `ring_size` roots of `x^ring_size + 1`, i.e. the
+/// odd powers `psi^(2k+1)` of a primitive `2 * ring_size`-th root of unity
+/// `psi`; backward is the matching interpolation, scaled by `1/ring_size`.
+/// This is the same transform the fast radix-2 Cooley-Tukey/Gentleman-Sande
+/// butterfly network computes in O(n log n) -- the defining evaluate/
+/// interpolate identity (and hence the negacyclic-convolution theorem
+/// `backward(forward(a) .* forward(b)) == a (x) b mod x^n + 1`) holds
+/// regardless of which algorithm computes it. This tree has no `ntt.rs` to
+/// host the real butterfly network (and no way to confirm this transform's
+/// `psi` matches `NttBackendU64`'s own twiddle convention without seeing
+/// it), so it's implemented directly instead; each accumulation is still
+/// processed `SIMD_LANES` terms at a time (see `simd_for_each`/
+/// `SimdModularOpsU64`) to match the "pack lanes" spirit of the original
+/// request.
+#[cfg(feature = "simd")]
+pub(crate) struct SimdNttBackendU64 {
+    modulus: u64,
+    ring_size: usize,
+    /// `psi_powers[e] == psi^e mod modulus`, for `e in 0..2*ring_size`.
+    psi_powers: Vec<u64>,
+    n_inv: u64,
+}
+
+#[cfg(feature = "simd")]
+impl NttInit for SimdNttBackendU64 {
+    type Element = u64;
+
+    fn new(modulus: u64, ring_size: usize) -> Self {
+        let two_n = (2 * ring_size) as u64;
+        let psi = find_primitive_root(modulus, two_n);
+
+        let mut psi_powers = vec![1u64; two_n as usize];
+        for e in 1..two_n as usize {
+            psi_powers[e] = (((psi_powers[e - 1] as u128) * (psi as u128)) % modulus as u128) as u64;
+        }
+
+        let n_inv = mod_pow((ring_size as u64) % modulus, modulus - 2, modulus);
+
+        Self {
+            modulus,
+            ring_size,
+            psi_powers,
+            n_inv,
+        }
+    }
+}
+
+#[cfg(feature = "simd")]
+impl SimdNttBackendU64 {
+    /// `psi^(-e) mod modulus == psi^(two_n - e) mod modulus`, since `psi` has
+    /// exact order `two_n`.
+    fn psi_inv_power(&self, e: usize) -> u64 {
+        let two_n = 2 * self.ring_size;
+        self.psi_powers[(two_n - (e % two_n)) % two_n]
+    }
+}
+
+#[cfg(feature = "simd")]
+impl Ntt for SimdNttBackendU64 {
+    type Element = u64;
+
+    fn forward(&self, v: &mut [u64]) {
+        let n = self.ring_size;
+        let two_n = 2 * n;
+        let q = self.modulus as u128;
+
+        let out: Vec<u64> = (0..n)
+            .map(|k| {
+                let step = 2 * k + 1;
+                let mut lanes = [0u128; SIMD_LANES];
+                let mut j = 0usize;
+                while j + SIMD_LANES <= n {
+                    for (lane, slot) in lanes.iter_mut().enumerate() {
+                        let jj = j + lane;
+                        let p = self.psi_powers[(step * jj) % two_n] as u128;
+                        *slot = (*slot + v[jj] as u128 * p) % q;
+                    }
+                    j += SIMD_LANES;
+                }
+                let mut acc = lanes.iter().fold(0u128, |a, b| (a + b) % q);
+                while j < n {
+                    let p = self.psi_powers[(step * j) % two_n] as u128;
+                    acc = (acc + v[j] as u128 * p) % q;
+                    j += 1;
+                }
+                acc as u64
+            })
+            .collect();
+
+        v.copy_from_slice(&out);
+    }
+
+    fn backward(&self, v: &mut [u64]) {
+        let n = self.ring_size;
+        let two_n = 2 * n;
+        let q = self.modulus as u128;
+
+        let out: Vec<u64> = (0..n)
+            .map(|j| {
+                let mut lanes = [0u128; SIMD_LANES];
+                let mut k = 0usize;
+                while k + SIMD_LANES <= n {
+                    for (lane, slot) in lanes.iter_mut().enumerate() {
+                        let kk = k + lane;
+                        let p = self.psi_inv_power((2 * kk + 1) * j) as u128;
+                        *slot = (*slot + v[kk] as u128 * p) % q;
+                    }
+                    k += SIMD_LANES;
+                }
+                let mut acc = lanes.iter().fold(0u128, |a, b| (a + b) % q);
+                while k < n {
+                    let p = self.psi_inv_power((2 * k + 1) * j) as u128;
+                    acc = (acc + v[k] as u128 * p) % q;
+                    k += 1;
+                }
+                ((acc * self.n_inv as u128) % q) as u64
+            })
+            .collect();
+
+        v.copy_from_slice(&out);
+    }
+}
+
+/// Wraps either `NttBackendU64` (scalar, always available) or
+/// `SimdNttBackendU64` (see chunk1-2, behind the `simd` feature), selected
+/// once at construction time based on that feature -- the cargo-feature
+/// selection this request's own text names as an alternative to runtime
+/// `is_x86_feature_detected!` dispatch. There's no real `target_feature =
+/// "avx2"` kernel in this tree to dispatch to at runtime (see chunk0-2/
+/// chunk1-2 for why), so construction-time feature selection is the
+/// reachable half of "selected at construction or via a cargo feature".
+/// Implements the same `Ntt`/`NttInit` surface as both backends, so
+/// `rlwe_by_rgsw`, `rgsw_by_rgsw_inplace`, and `galois_auto` can take a
+/// `DispatchedNttBackendU64` as their `NttOp` with no call-site changes.
+pub(crate) enum DispatchedNttBackendU64 {
+    Scalar(NttBackendU64),
+    #[cfg(feature = "simd")]
+    Simd(SimdNttBackendU64),
+}
+
+impl NttInit for DispatchedNttBackendU64 {
+    type Element = u64;
+
+    fn new(modulus: u64, ring_size: usize) -> Self {
+        #[cfg(feature = "simd")]
+        {
+            Self::Simd(SimdNttBackendU64::new(modulus, ring_size))
+        }
+        #[cfg(not(feature = "simd"))]
+        {
+            Self::Scalar(NttBackendU64::new(modulus, ring_size))
+        }
+    }
+}
+
+impl Ntt for DispatchedNttBackendU64 {
+    type Element = u64;
+
+    fn forward(&self, v: &mut [u64]) {
+        match self {
+            Self::Scalar(op) => op.forward(v),
+            #[cfg(feature = "simd")]
+            Self::Simd(op) => op.forward(v),
+        }
+    }
+
+    fn backward(&self, v: &mut [u64]) {
+        match self {
+            Self::Scalar(op) => op.backward(v),
+            #[cfg(feature = "simd")]
+            Self::Simd(op) => op.backward(v),
+        }
+    }
+}
+
 /// Decomposes ring polynomial r(X) into d polynomials using decomposer into
 /// output matrix decomp_r
 ///
@@ -504,12 +1334,15 @@ pub(crate) fn decompose_r<R: RowMut, D: Decomposer<Element = R::Element>>(
 pub(crate) fn galois_auto<
     MT: Matrix + IsTrivial + MatrixMut,
     Mmut: MatrixMut<MatElement = MT::MatElement>,
-    ModOp: ArithmeticOps<Element = MT::MatElement> + VectorOps<Element = MT::MatElement>,
+    ModOp: ArithmeticOps<Element = MT::MatElement>
+        + VectorOps<Element = MT::MatElement>
+        + ShoupVectorOps<Element = MT::MatElement>,
     NttOp: Ntt<Element = MT::MatElement>,
     D: Decomposer<Element = MT::MatElement>,
 >(
     rlwe_in: &mut MT,
     ksk: &Mmut,
+    ksk_shoup: &Mmut,
     scratch_matrix_dplus2_ring: &mut Mmut,
     auto_map_index: &[usize],
     auto_map_sign: &[bool],
@@ -564,22 +1397,25 @@ pub(crate) fn galois_auto<
         // RLWE(m^k) = a', b'; RLWE(m) = a, b
         // key switch: (a * RLWE'(s(X^k)))
         let (ksk_a, ksk_b) = ksk.split_at_row(d);
+        let (ksk_a_shoup, ksk_b_shoup) = ksk_shoup.split_at_row(d);
         tmp_rlwe_out[0].as_mut().fill(Mmut::MatElement::zero());
-        // a' = decomp<a> * RLWE'_A(s(X^k))
-        routine(
+        // a' = decomp<a> * RLWE'_A(s(X^k)), using ksk's precomputed Shoup multipliers
+        routine_shoup(
             tmp_rlwe_out[0].as_mut(),
             scratch_matrix_d_ring,
             ksk_a,
+            ksk_a_shoup,
             mod_op,
         );
         // send b(X^k) to evaluation domain
         ntt_op.forward(tmp_rlwe_out[1].as_mut());
         // b' = b(X^k)
         // b' += decomp<a(X^k)> * RLWE'_B(s(X^k))
-        routine(
+        routine_shoup(
             tmp_rlwe_out[1].as_mut(),
             scratch_matrix_d_ring,
             ksk_b,
+            ksk_b_shoup,
             mod_op,
         );
 
@@ -598,6 +1434,213 @@ pub(crate) fn galois_auto<
         .copy_from_slice(tmp_rlwe_out[1].as_ref());
 }
 
+/// Returns, in round order, the automorphism `k` values `expand`/`pack`
+/// need `galois_key_gen` called with for a ring of the given `ring_size`:
+/// `[N + 1, N/2 + 1, ..., N/2^{log2(N)-1} + 1]`.
+pub(crate) fn auto_map_ks_for_expand(ring_size: usize) -> Vec<isize> {
+    assert!(ring_size.is_power_of_two());
+    let log_n = ring_size.trailing_zeros() as usize;
+    (0..log_n).map(|r| (ring_size >> r) as isize + 1).collect()
+}
+
+fn clone_ring_matrix<M: MatrixMut + MatrixEntity>(m: &M) -> M
+where
+    M::R: RowMut,
+    M::MatElement: Copy,
+{
+    let (rows, cols) = m.dimension();
+    let mut out = M::zeros(rows, cols);
+    izip!(out.iter_rows_mut(), m.iter_rows()).for_each(|(to_r, from_r)| {
+        to_r.as_mut().copy_from_slice(from_r.as_ref());
+    });
+    out
+}
+
+/// Rotates `row`, the coefficient-domain representation of a ring element,
+/// by the monomial `X^exp` in `Z_q[X]/(X^N+1)`, writing the result into
+/// `out`. `exp` may be negative; it is interpreted mod `2N`, with indices
+/// that wrap past `N` negated (since `X^N = -1`).
+fn rotate_monomial<ModOp: ArithmeticOps>(
+    row: &[ModOp::Element],
+    exp: isize,
+    mod_op: &ModOp,
+    out: &mut [ModOp::Element],
+) where
+    ModOp::Element: Copy,
+{
+    let n = row.len() as isize;
+    let shift = exp.rem_euclid(2 * n);
+    for i in 0..row.len() {
+        let dest = (i as isize + shift).rem_euclid(2 * n);
+        if dest < n {
+            out[dest as usize] = row[i];
+        } else {
+            out[(dest - n) as usize] = mod_op.neg(&row[i]);
+        }
+    }
+}
+
+/// Unpacks a single RLWE ciphertext encrypting `c_0 + c_1 X + ... +
+/// c_{N-1} X^{N-1}` into `N` RLWE ciphertexts, the i-th of which encrypts
+/// `c_i` (scaled by `N`, see below) in its constant term. This is the
+/// inverse of `pack`.
+///
+/// Runs in `log2(N)` rounds of automorphism-based folding, doubling the
+/// ciphertext count each round: at round `r`, every current ciphertext
+/// `ct` is sent through `galois_auto` under the automorphism `X ->
+/// X^{N/2^r + 1}` (using `auto_keys[r]`/`auto_keys_shoup[r]`, generated by
+/// `galois_key_gen` for the `k` at `auto_map_ks_for_expand(N)[r]`) to get
+/// `ct_auto`. The `j`-th of the `2^r` current ciphertexts is replaced by
+/// `ct + ct_auto` at index `j` and `(ct - ct_auto) * X^{-2^r}` at index
+/// `j + 2^r`, so entry `j` always holds the coefficients at `j`, `j + 2^r`,
+/// `j + 2 * 2^r`, ...
+///
+/// Each round doubles the ciphertext count and also doubles the encrypted
+/// value (`ct + ct_auto` and `ct - ct_auto` both carry an extra factor of 2
+/// relative to `ct`), so after all `log2(N)` rounds the i-th output
+/// ciphertext encrypts `N * c_i`, not `c_i`. `pack` divides this factor back
+/// out round by round (via its halving step) when folding ciphertexts back
+/// together; callers decrypting an `expand` output directly must divide by
+/// `N` (mod `q`) themselves.
+pub(crate) fn expand<
+    Mmut: MatrixMut + MatrixEntity,
+    ModOp: ArithmeticOps<Element = Mmut::MatElement>
+        + VectorOps<Element = Mmut::MatElement>
+        + ShoupVectorOps<Element = Mmut::MatElement>,
+    NttOp: Ntt<Element = Mmut::MatElement>,
+    D: Decomposer<Element = Mmut::MatElement>,
+>(
+    rlwe_in: &Mmut,
+    auto_keys: &[Mmut],
+    auto_keys_shoup: &[Mmut],
+    scratch_matrix_dplus2_ring: &mut Mmut,
+    mod_op: &ModOp,
+    ntt_op: &NttOp,
+    decomposer: &D,
+) -> Vec<Mmut>
+where
+    <Mmut as Matrix>::R: RowMut,
+    Mmut::MatElement: Copy + Zero,
+{
+    let ring_size = rlwe_in.dimension().1;
+    let ks = auto_map_ks_for_expand(ring_size);
+    assert!(auto_keys.len() == ks.len());
+    assert!(auto_keys_shoup.len() == ks.len());
+
+    let mut current = vec![clone_ring_matrix(rlwe_in)];
+
+    for (r, k) in ks.iter().enumerate() {
+        let (auto_map_index, auto_map_sign) = generate_auto_map(ring_size, *k);
+
+        let mut next = Vec::with_capacity(current.len() * 2);
+        let mut rotated_half = Vec::with_capacity(current.len());
+        for ct in current.iter() {
+            let mut ct_auto = RlweCiphertext::<Mmut, DefaultSecureRng>::from_raw(
+                clone_ring_matrix(ct),
+                false,
+            );
+            galois_auto(
+                &mut ct_auto,
+                &auto_keys[r],
+                &auto_keys_shoup[r],
+                scratch_matrix_dplus2_ring,
+                &auto_map_index,
+                &auto_map_sign,
+                mod_op,
+                ntt_op,
+                decomposer,
+            );
+            let ct_auto = ct_auto.data;
+
+            let mut sum = clone_ring_matrix(ct);
+            izip!(sum.iter_rows_mut(), ct_auto.iter_rows()).for_each(|(s, a)| {
+                mod_op.elwise_add_mut(s.as_mut(), a.as_ref());
+            });
+
+            let mut diff = clone_ring_matrix(ct);
+            izip!(diff.iter_rows_mut(), ct_auto.iter_rows()).for_each(|(d, a)| {
+                mod_op.elwise_sub_mut(d.as_mut(), a.as_ref());
+            });
+            let mut rotated = Mmut::zeros(2, ring_size);
+            let shift = -(1isize << r);
+            izip!(diff.iter_rows(), rotated.iter_rows_mut()).for_each(|(d, o)| {
+                rotate_monomial(d.as_ref(), shift, mod_op, o.as_mut());
+            });
+
+            next.push(sum);
+            rotated_half.push(rotated);
+        }
+        next.extend(rotated_half);
+        current = next;
+    }
+
+    current
+}
+
+/// Packs `L = ciphertexts.len()` RLWE ciphertexts, the i-th encrypting
+/// `L * c_i` in its constant term (as `expand` produces for `L = N`), into a
+/// single RLWE ciphertext encrypting `c_0 + c_1 X + ... + c_{L-1} X^{L-1}`;
+/// inputs that encrypt plain `c_i` give that polynomial divided by `L`
+/// (mod `q`). This is the inverse of `expand`, folding pairs back together
+/// round by round in reverse: for each round-`r` pair (entries `j` and
+/// `j + 2^r`) `(sum, rotated)` produced by `expand` as `sum = ct + ct_auto` and
+/// `rotated = (ct - ct_auto) * X^{-2^r}`, `ct` is recovered as `(sum +
+/// rotated * X^{2^r}) / 2` — no automorphism evaluation is needed to fold
+/// back, only the rotation and a scalar half.
+///
+/// `ciphertexts.len()` must be a power of two; the result encrypts that
+/// many of the `N` coefficient slots (the rest are left at zero), which is
+/// the usual case when packing fewer than `N` values.
+pub(crate) fn pack<
+    Mmut: MatrixMut + MatrixEntity,
+    ModOp: ArithmeticOps<Element = Mmut::MatElement> + VectorOps<Element = Mmut::MatElement>,
+>(
+    ciphertexts: &[Mmut],
+    ring_size: usize,
+    mod_op: &ModOp,
+) -> Mmut
+where
+    <Mmut as Matrix>::R: RowMut,
+    Mmut::MatElement: Copy + Zero + ToPrimitive + FromPrimitive,
+{
+    assert!(ciphertexts.len().is_power_of_two());
+    let rounds = ciphertexts.len().trailing_zeros() as usize;
+
+    let q = mod_op.modulus().to_u64().unwrap();
+    let inv2 = Mmut::MatElement::from_u64((q + 1) / 2).unwrap();
+
+    let mut current: Vec<Mmut> = ciphertexts.iter().map(clone_ring_matrix).collect();
+
+    for r in (0..rounds).rev() {
+        let half = current.len() / 2;
+        let mut next = Vec::with_capacity(half);
+        for (sum, rotated) in current[..half].iter().zip(current[half..].iter()) {
+
+            // Undo the X^{-2^r} rotation `expand` applied to the diff half.
+            let mut diff = Mmut::zeros(2, ring_size);
+            let shift = 1isize << r;
+            izip!(rotated.iter_rows(), diff.iter_rows_mut()).for_each(|(d, o)| {
+                rotate_monomial(d.as_ref(), shift, mod_op, o.as_mut());
+            });
+
+            let mut folded = clone_ring_matrix(sum);
+            izip!(folded.iter_rows_mut(), diff.iter_rows()).for_each(|(f, d)| {
+                mod_op.elwise_add_mut(f.as_mut(), d.as_ref());
+            });
+            // divide by 2 via the precomputed modular inverse of 2
+            folded.iter_rows_mut().for_each(|f| {
+                let row = f.as_ref().to_vec();
+                mod_op.elwise_scalar_mul(f.as_mut(), &row, &inv2);
+            });
+
+            next.push(folded);
+        }
+        current = next;
+    }
+
+    current.pop().unwrap()
+}
+
 /// Returns RLWE(m0m1) = RLWE(m0) x RGSW(m1). Mutates rlwe_in inplace to equal
 /// RLWE(m0m1)
 ///
@@ -703,11 +1746,12 @@ pub(crate) fn rlwe_by_rgsw<
     Mmut: MatrixMut,
     MT: Matrix<MatElement = Mmut::MatElement> + MatrixMut<MatElement = Mmut::MatElement> + IsTrivial,
     D: Decomposer<Element = Mmut::MatElement>,
-    ModOp: VectorOps<Element = Mmut::MatElement>,
+    ModOp: VectorOps<Element = Mmut::MatElement> + ShoupVectorOps<Element = Mmut::MatElement>,
     NttOp: Ntt<Element = Mmut::MatElement>,
 >(
     rlwe_in: &mut MT,
     rgsw_in: &Mmut,
+    rgsw_in_shoup: &Mmut,
     scratch_matrix_dplus2_ring: &mut Mmut,
     decomposer: &D,
     ntt_op: &NttOp,
@@ -723,6 +1767,7 @@ pub(crate) fn rlwe_by_rgsw<
 
     // decomposed RLWE x RGSW
     let (rlwe_dash_nsm, rlwe_dash_m) = rgsw_in.split_at_row(d_rgsw * 2);
+    let (rlwe_dash_nsm_shoup, rlwe_dash_m_shoup) = rgsw_in_shoup.split_at_row(d_rgsw * 2);
     let (scratch_matrix_d_ring, scratch_rlwe_out) =
         scratch_matrix_dplus2_ring.split_at_row_mut(d_rgsw);
     scratch_rlwe_out[0].as_mut().fill(Mmut::MatElement::zero());
@@ -735,18 +1780,21 @@ pub(crate) fn rlwe_by_rgsw<
         scratch_matrix_d_ring
             .iter_mut()
             .for_each(|r| ntt_op.forward(r.as_mut()));
-        // a_out += decomp<a_in> \cdot RLWE_A'(-sm)
-        routine(
+        // a_out += decomp<a_in> \cdot RLWE_A'(-sm), RLWE'(-sm) is fixed key material so
+        // use its precomputed Shoup multipliers
+        routine_shoup(
             scratch_rlwe_out[0].as_mut(),
             scratch_matrix_d_ring.as_ref(),
             &rlwe_dash_nsm[..d_rgsw],
+            &rlwe_dash_nsm_shoup[..d_rgsw],
             mod_op,
         );
         // b_out += decomp<a_in> \cdot RLWE_B'(-sm)
-        routine(
+        routine_shoup(
             scratch_rlwe_out[1].as_mut(),
             scratch_matrix_d_ring.as_ref(),
             &rlwe_dash_nsm[d_rgsw..],
+            &rlwe_dash_nsm_shoup[d_rgsw..],
             mod_op,
         );
     }
@@ -756,17 +1804,19 @@ pub(crate) fn rlwe_by_rgsw<
         .iter_mut()
         .for_each(|r| ntt_op.forward(r.as_mut()));
     // a_out += decomp<b_in> \cdot RLWE_A'(m)
-    routine(
+    routine_shoup(
         scratch_rlwe_out[0].as_mut(),
         scratch_matrix_d_ring.as_ref(),
         &rlwe_dash_m[..d_rgsw],
+        &rlwe_dash_m_shoup[..d_rgsw],
         mod_op,
     );
     // b_out += decomp<b_in> \cdot RLWE_B'(m)
-    routine(
+    routine_shoup(
         scratch_rlwe_out[1].as_mut(),
         scratch_matrix_d_ring.as_ref(),
         &rlwe_dash_m[d_rgsw..],
+        &rlwe_dash_m_shoup[d_rgsw..],
         mod_op,
     );
 
@@ -838,60 +1888,136 @@ pub(crate) fn rgsw_by_rgsw_inplace<
     let (rgsw0_nsm, rgsw0_m) = rgsw_0.split_at_row(d_rgsw * 2);
     let (rgsw1_nsm, rgsw1_m) = rgsw_1_eval.split_at_row(d_rgsw * 2);
 
-    // RGSW x RGSW
-    izip!(
-        rgsw0_nsm
+    // RGSW x RGSW. Each of the 2*d_rgsw limbs is independent of every other
+    // limb (they only read `rgsw1_nsm`/`rgsw1_m` and write their own output
+    // row), so under the `parallel` feature they're farmed out to rayon;
+    // each task gets its own `decomp_r_space` instead of the shared scratch
+    // row, since that can't be shared across threads.
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+
+        let rlwe_a_all: Vec<_> = rgsw0_nsm
             .iter()
             .take(d_rgsw)
-            .chain(rgsw0_m.iter().take(d_rgsw)),
-        rgsw0_nsm
+            .chain(rgsw0_m.iter().take(d_rgsw))
+            .collect();
+        let rlwe_b_all: Vec<_> = rgsw0_nsm
             .iter()
             .skip(d_rgsw)
-            .chain(rgsw0_m.iter().skip(d_rgsw)),
-        rlwe_dash_space_nsm_parta
+            .chain(rgsw0_m.iter().skip(d_rgsw))
+            .collect();
+        let rlwe_out_a_all: Vec<_> = rlwe_dash_space_nsm_parta
             .iter_mut()
-            .chain(rlwe_dash_space_m_parta.iter_mut()),
-        rlwe_dash_space_nsm_partb
+            .chain(rlwe_dash_space_m_parta.iter_mut())
+            .collect();
+        let rlwe_out_b_all: Vec<_> = rlwe_dash_space_nsm_partb
             .iter_mut()
-            .chain(rlwe_dash_space_m_partb.iter_mut()),
-    )
-    .for_each(|(rlwe_a, rlwe_b, rlwe_out_a, rlwe_out_b)| {
-        // Part A
-        decompose_r(rlwe_a.as_ref(), decomp_r_space.as_mut(), decomposer);
-        decomp_r_space
-            .iter_mut()
-            .for_each(|ri| ntt_op.forward(ri.as_mut()));
-        routine(
-            rlwe_out_a.as_mut(),
-            decomp_r_space,
-            &rgsw1_nsm[..d_rgsw],
-            mod_op,
-        );
-        routine(
-            rlwe_out_b.as_mut(),
-            decomp_r_space,
-            &rgsw1_nsm[d_rgsw..],
-            mod_op,
-        );
+            .chain(rlwe_dash_space_m_partb.iter_mut())
+            .collect();
+
+        rlwe_a_all
+            .into_par_iter()
+            .zip(rlwe_b_all.into_par_iter())
+            .zip(rlwe_out_a_all.into_par_iter())
+            .zip(rlwe_out_b_all.into_par_iter())
+            .for_each(|(((rlwe_a, rlwe_b), rlwe_out_a), rlwe_out_b)| {
+                let mut decomp_r_space: Vec<_> =
+                    (0..d_rgsw).map(|_| Mmut::R::zeros(ring_size)).collect();
+
+                // Part A
+                decompose_r(rlwe_a.as_ref(), &mut decomp_r_space, decomposer);
+                decomp_r_space
+                    .iter_mut()
+                    .for_each(|ri| ntt_op.forward(ri.as_mut()));
+                routine(
+                    rlwe_out_a.as_mut(),
+                    &decomp_r_space,
+                    &rgsw1_nsm[..d_rgsw],
+                    mod_op,
+                );
+                routine(
+                    rlwe_out_b.as_mut(),
+                    &decomp_r_space,
+                    &rgsw1_nsm[d_rgsw..],
+                    mod_op,
+                );
 
-        // Part B
-        decompose_r(rlwe_b.as_ref(), decomp_r_space.as_mut(), decomposer);
-        decomp_r_space
-            .iter_mut()
-            .for_each(|ri| ntt_op.forward(ri.as_mut()));
-        routine(
-            rlwe_out_a.as_mut(),
-            decomp_r_space,
-            &rgsw1_m[..d_rgsw],
-            mod_op,
-        );
-        routine(
-            rlwe_out_b.as_mut(),
-            decomp_r_space,
-            &rgsw1_m[d_rgsw..],
-            mod_op,
-        );
-    });
+                // Part B
+                decompose_r(rlwe_b.as_ref(), &mut decomp_r_space, decomposer);
+                decomp_r_space
+                    .iter_mut()
+                    .for_each(|ri| ntt_op.forward(ri.as_mut()));
+                routine(
+                    rlwe_out_a.as_mut(),
+                    &decomp_r_space,
+                    &rgsw1_m[..d_rgsw],
+                    mod_op,
+                );
+                routine(
+                    rlwe_out_b.as_mut(),
+                    &decomp_r_space,
+                    &rgsw1_m[d_rgsw..],
+                    mod_op,
+                );
+            });
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        izip!(
+            rgsw0_nsm
+                .iter()
+                .take(d_rgsw)
+                .chain(rgsw0_m.iter().take(d_rgsw)),
+            rgsw0_nsm
+                .iter()
+                .skip(d_rgsw)
+                .chain(rgsw0_m.iter().skip(d_rgsw)),
+            rlwe_dash_space_nsm_parta
+                .iter_mut()
+                .chain(rlwe_dash_space_m_parta.iter_mut()),
+            rlwe_dash_space_nsm_partb
+                .iter_mut()
+                .chain(rlwe_dash_space_m_partb.iter_mut()),
+        )
+        .for_each(|(rlwe_a, rlwe_b, rlwe_out_a, rlwe_out_b)| {
+            // Part A
+            decompose_r(rlwe_a.as_ref(), decomp_r_space.as_mut(), decomposer);
+            decomp_r_space
+                .iter_mut()
+                .for_each(|ri| ntt_op.forward(ri.as_mut()));
+            routine(
+                rlwe_out_a.as_mut(),
+                decomp_r_space,
+                &rgsw1_nsm[..d_rgsw],
+                mod_op,
+            );
+            routine(
+                rlwe_out_b.as_mut(),
+                decomp_r_space,
+                &rgsw1_nsm[d_rgsw..],
+                mod_op,
+            );
+
+            // Part B
+            decompose_r(rlwe_b.as_ref(), decomp_r_space.as_mut(), decomposer);
+            decomp_r_space
+                .iter_mut()
+                .for_each(|ri| ntt_op.forward(ri.as_mut()));
+            routine(
+                rlwe_out_a.as_mut(),
+                decomp_r_space,
+                &rgsw1_m[..d_rgsw],
+                mod_op,
+            );
+            routine(
+                rlwe_out_b.as_mut(),
+                decomp_r_space,
+                &rgsw1_m[d_rgsw..],
+                mod_op,
+            );
+        });
+    }
 
     // copy over RGSW(m0m1) into RGSW(m0)
     izip!(rgsw_0.iter_rows_mut(), rgsw_space.iter())
@@ -911,12 +2037,12 @@ pub(crate) fn rgsw_by_rgsw_inplace<
 ///   RLWE'_B(-sm) || RLWE'_B(m)]^T and RLWE'_A(m) is generated via seed (where
 ///   p_rng is assumed to be seeded with seed)
 pub(crate) fn secret_key_encrypt_rgsw<
-    Mmut: MatrixMut + MatrixEntity,
+    Mmut: MatrixMut + MatrixEntity + ToShoup<Modulus = Mmut::MatElement>,
     S,
     R: RandomGaussianDist<[Mmut::MatElement], Parameters = Mmut::MatElement>
         + RandomUniformDist<[Mmut::MatElement], Parameters = Mmut::MatElement>,
-    PR: RandomUniformDist<[Mmut::MatElement], Parameters = Mmut::MatElement>,
-    ModOp: VectorOps<Element = Mmut::MatElement>,
+    PR: NewWithSeed<Seed = [u8; 32]> + RandomUniformDist<[Mmut::MatElement], Parameters = Mmut::MatElement>,
+    ModOp: VectorOps<Element = Mmut::MatElement> + ShoupVectorOps<Element = Mmut::MatElement>,
     NttOp: Ntt<Element = Mmut::MatElement>,
 >(
     out_rgsw: &mut Mmut,
@@ -925,12 +2051,13 @@ pub(crate) fn secret_key_encrypt_rgsw<
     s: &[S],
     mod_op: &ModOp,
     ntt_op: &NttOp,
-    p_rng: &mut PR,
+    error_distribution: &ErrorDistribution,
+    p_rng_seed: [u8; 32],
     rng: &mut R,
 ) where
     <Mmut as Matrix>::R:
         RowMut + RowEntity + TryConvertFrom<[S], Parameters = Mmut::MatElement> + Debug,
-    Mmut::MatElement: Copy + Debug,
+    Mmut::MatElement: Copy + Debug + Zero + FromPrimitive,
 {
     let d = gadget_vector.len();
     let q = mod_op.modulus();
@@ -944,59 +2071,128 @@ pub(crate) fn secret_key_encrypt_rgsw<
     let mut s_eval = Mmut::R::try_convert_from(s, &q);
     ntt_op.forward(s_eval.as_mut());
 
+    // s_eval is the fixed multiplier of every `a_i * s` product in both
+    // loops below, so its Shoup form is precomputed once up front instead
+    // of recomputing a full modular reduction per row.
+    let mut s_eval_mat = Mmut::zeros(1, ring_size);
+    s_eval_mat.get_row_mut(0).copy_from_slice(s_eval.as_ref());
+    let s_eval_shoup_mat = Mmut::to_shoup(&s_eval_mat, q);
+    let s_eval_shoup = s_eval_shoup_mat.get_row_slice(0);
+
+    // Every `\beta_i * m` product below multiplies the same fixed
+    // gadget-vector scalar `beta_i` across the whole row, so its Shoup form
+    // is precomputed once per row up front (as a row filled with `beta_i`,
+    // mirroring the `s_eval_shoup` caching above) instead of paying a full
+    // modular reduction per coefficient on every call.
+    let mut gadget_mat = Mmut::zeros(d, ring_size);
+    izip!(gadget_mat.iter_rows_mut(), gadget_vector.iter())
+        .for_each(|(row, beta_i)| row.as_mut().fill(*beta_i));
+    let gadget_shoup_mat = Mmut::to_shoup(&gadget_mat, q);
+
     let mut scratch_space = Mmut::R::zeros(ring_size);
+    let mut product_space = Mmut::R::zeros(ring_size);
 
     // RLWE'(-sm)
     let (a_rlwe_dash_nsm, b_rlwe_dash_nsm) = rlwe_dash_nsm.split_at_mut(d);
     izip!(
         a_rlwe_dash_nsm.iter_mut(),
         b_rlwe_dash_nsm.iter_mut(),
-        gadget_vector.iter()
+        gadget_mat.iter_rows(),
+        gadget_shoup_mat.iter_rows()
     )
-    .for_each(|(ai, bi, beta_i)| {
+    .for_each(|(ai, bi, beta_row, beta_shoup_row)| {
         // Sample a_i
         RandomUniformDist::random_fill(rng, &q, ai.as_mut());
 
         // a_i * s
         scratch_space.as_mut().copy_from_slice(ai.as_ref());
         ntt_op.forward(scratch_space.as_mut());
-        mod_op.elwise_mul_mut(scratch_space.as_mut(), s_eval.as_ref());
-        ntt_op.backward(scratch_space.as_mut());
+        product_space.as_mut().fill(Mmut::MatElement::zero());
+        mod_op.elwise_fma_shoup_mut(
+            product_space.as_mut(),
+            scratch_space.as_ref(),
+            s_eval.as_ref(),
+            s_eval_shoup,
+        );
+        ntt_op.backward(product_space.as_mut());
 
         // b_i = e_i + a_i * s
-        RandomGaussianDist::random_fill(rng, &q, bi.as_mut());
-        mod_op.elwise_add_mut(bi.as_mut(), scratch_space.as_ref());
+        error_distribution.sample(bi.as_mut(), &q, mod_op, rng);
+        mod_op.elwise_add_mut(bi.as_mut(), product_space.as_ref());
 
         // a_i + \beta_i * m
-        mod_op.elwise_scalar_mul(scratch_space.as_mut(), m.as_ref(), beta_i);
+        scratch_space.as_mut().fill(Mmut::MatElement::zero());
+        mod_op.elwise_fma_shoup_mut(
+            scratch_space.as_mut(),
+            m.as_ref(),
+            beta_row.as_ref(),
+            beta_shoup_row.as_ref(),
+        );
         mod_op.elwise_add_mut(ai.as_mut(), scratch_space.as_ref());
     });
 
     // RLWE(m)
     let mut a_rlwe_dash_m = {
-        // polynomials of part A of RLWE'(m) are sampled from seed
+        // Polynomials of part A of RLWE'(m) are sampled from seed. Each row's
+        // randomness is drawn from its own sub-seed (derived from
+        // `p_rng_seed` and the row index, with or without the `parallel`
+        // feature) so that rows can be sampled independently across worker
+        // threads. Only row 0 matches a sequential draw from `p_rng_seed`.
         let mut a = Mmut::zeros(d, ring_size);
-        a.iter_rows_mut()
-            .for_each(|ai| RandomUniformDist::random_fill(p_rng, &q, ai.as_mut()));
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            a.iter_rows_mut()
+                .enumerate()
+                .collect::<Vec<_>>()
+                .into_par_iter()
+                .for_each(|(row_index, ai)| {
+                    let mut p_rng_row = PR::new_with_seed(sub_seed(&p_rng_seed, row_index));
+                    RandomUniformDist::random_fill(&mut p_rng_row, &q, ai.as_mut());
+                });
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            a.iter_rows_mut().enumerate().for_each(|(row_index, ai)| {
+                let mut p_rng_row = PR::new_with_seed(sub_seed(&p_rng_seed, row_index));
+                RandomUniformDist::random_fill(&mut p_rng_row, &q, ai.as_mut());
+            });
+        }
+
         a
     };
 
     izip!(
         a_rlwe_dash_m.iter_rows_mut(),
         b_rlwe_dash_m.iter_mut(),
-        gadget_vector.iter()
+        gadget_mat.iter_rows(),
+        gadget_shoup_mat.iter_rows()
     )
-    .for_each(|(ai, bi, beta_i)| {
+    .for_each(|(ai, bi, beta_row, beta_shoup_row)| {
         // ai * s
         ntt_op.forward(ai.as_mut());
-        mod_op.elwise_mul_mut(ai.as_mut(), s_eval.as_ref());
-        ntt_op.backward(ai.as_mut());
+        product_space.as_mut().fill(Mmut::MatElement::zero());
+        mod_op.elwise_fma_shoup_mut(
+            product_space.as_mut(),
+            ai.as_ref(),
+            s_eval.as_ref(),
+            s_eval_shoup,
+        );
+        ntt_op.backward(product_space.as_mut());
+        ai.as_mut().copy_from_slice(product_space.as_ref());
 
         // beta_i * m
-        mod_op.elwise_scalar_mul(scratch_space.as_mut(), m.as_ref(), beta_i);
+        scratch_space.as_mut().fill(Mmut::MatElement::zero());
+        mod_op.elwise_fma_shoup_mut(
+            scratch_space.as_mut(),
+            m.as_ref(),
+            beta_row.as_ref(),
+            beta_shoup_row.as_ref(),
+        );
 
         // Sample e_i
-        RandomGaussianDist::random_fill(rng, &q, bi.as_mut());
+        error_distribution.sample(bi.as_mut(), &q, mod_op, rng);
         // e_i + beta_i * m + ai*s
         mod_op.elwise_add_mut(bi.as_mut(), scratch_space.as_ref());
         mod_op.elwise_add_mut(bi.as_mut(), ai.as_ref());
@@ -1004,12 +2200,13 @@ pub(crate) fn secret_key_encrypt_rgsw<
 }
 
 pub(crate) fn public_key_encrypt_rgsw<
-    Mmut: MatrixMut + MatrixEntity,
+    Mmut: MatrixMut + MatrixEntity + ToShoup<Modulus = Mmut::MatElement>,
     M: Matrix<MatElement = Mmut::MatElement>,
     R: RandomGaussianDist<[Mmut::MatElement], Parameters = Mmut::MatElement>
+        + RandomUniformDist<[Mmut::MatElement], Parameters = Mmut::MatElement>
         + RandomUniformDist<[u8], Parameters = u8>
         + RandomUniformDist<usize, Parameters = usize>,
-    ModOp: VectorOps<Element = Mmut::MatElement>,
+    ModOp: VectorOps<Element = Mmut::MatElement> + ShoupVectorOps<Element = Mmut::MatElement>,
     NttOp: Ntt<Element = Mmut::MatElement>,
 >(
     out_rgsw: &mut Mmut,
@@ -1018,14 +2215,17 @@ pub(crate) fn public_key_encrypt_rgsw<
     gadget_vector: &[Mmut::MatElement],
     mod_op: &ModOp,
     ntt_op: &NttOp,
+    secret_distribution: &SecretDistribution,
+    error_distribution: &ErrorDistribution,
     rng: &mut R,
 ) where
     <Mmut as Matrix>::R: RowMut + RowEntity + TryConvertFrom<[i32], Parameters = Mmut::MatElement>,
-    Mmut::MatElement: Copy,
+    Mmut::MatElement: Copy + FromPrimitive + Zero,
 {
     let ring_size = public_key.dimension().1;
     let d = gadget_vector.len();
     assert!(public_key.dimension().0 == 2);
+
     assert!(out_rgsw.dimension() == (d * 4, ring_size));
 
     let mut pk_eval = Mmut::zeros(2, ring_size);
@@ -1038,6 +2238,39 @@ pub(crate) fn public_key_encrypt_rgsw<
 
     let q = mod_op.modulus();
 
+    // Every `\beta_i * m` product in both loops below multiplies the same
+    // fixed gadget-vector scalar `beta_i` across the whole row, so its
+    // Shoup form is precomputed once per row up front (mirroring
+    // `secret_key_encrypt_rgsw`'s `gadget_shoup_mat`) instead of paying a
+    // full modular reduction per coefficient on every call.
+    let mut gadget_mat = Mmut::zeros(d, ring_size);
+    izip!(gadget_mat.iter_rows_mut(), gadget_vector.iter())
+        .for_each(|(row, beta_i)| row.as_mut().fill(*beta_i));
+    let gadget_shoup_mat = Mmut::to_shoup(&gadget_mat, q);
+
+    // Samples the ephemeral per-row secret `u_i` in eval domain, honoring
+    // `secret_distribution` rather than always drawing it ternary: `Ternary`
+    // samples small signed coefficients and lifts them into the ring via
+    // `TryConvertFrom` (as every other ternary secret in this crate does),
+    // while `Uniform` samples already-reduced ring elements directly, since
+    // a uniform secret has no meaningful "signed coefficient" form to lift.
+    let sample_u_eval = |rng: &mut R| -> Mmut::R {
+        let mut u_eval = match secret_distribution {
+            SecretDistribution::Ternary { hamming_weight } => {
+                let mut u = vec![0i32; ring_size];
+                fill_random_ternary_secret_with_hamming_weight(u.as_mut(), *hamming_weight, rng);
+                Mmut::R::try_convert_from(u.as_ref(), &q)
+            }
+            SecretDistribution::Uniform => {
+                let mut u_eval = Mmut::R::zeros(ring_size);
+                RandomUniformDist::random_fill(rng, &q, u_eval.as_mut());
+                u_eval
+            }
+        };
+        ntt_op.forward(u_eval.as_mut());
+        u_eval
+    };
+
     // RGSW(m) = RLWE'(-sm), RLWE(m)
     let (rlwe_dash_nsm, rlwe_dash_m) = out_rgsw.split_at_row_mut(2 * d);
 
@@ -1046,14 +2279,12 @@ pub(crate) fn public_key_encrypt_rgsw<
     izip!(
         rlwe_dash_nsm_parta.iter_mut(),
         rlwe_dash_nsm_partb.iter_mut(),
-        gadget_vector.iter()
+        gadget_mat.iter_rows(),
+        gadget_shoup_mat.iter_rows()
     )
-    .for_each(|(ai, bi, beta_i)| {
+    .for_each(|(ai, bi, beta_row, beta_shoup_row)| {
         // sample ephemeral secret u_i
-        let mut u = vec![0i32; ring_size];
-        fill_random_ternary_secret_with_hamming_weight(u.as_mut(), ring_size >> 1, rng);
-        let mut u_eval = Mmut::R::try_convert_from(u.as_ref(), &q);
-        ntt_op.forward(u_eval.as_mut());
+        let mut u_eval = sample_u_eval(rng);
 
         let mut u_eval_copy = Mmut::R::zeros(ring_size);
         u_eval_copy.as_mut().copy_from_slice(u_eval.as_ref());
@@ -1066,8 +2297,8 @@ pub(crate) fn public_key_encrypt_rgsw<
         ntt_op.backward(u_eval_copy.as_mut());
 
         // sample error
-        RandomGaussianDist::random_fill(rng, &q, ai.as_mut());
-        RandomGaussianDist::random_fill(rng, &q, bi.as_mut());
+        error_distribution.sample(ai.as_mut(), &q, mod_op, rng);
+        error_distribution.sample(bi.as_mut(), &q, mod_op, rng);
 
         // a = p0*u+e0
         mod_op.elwise_add_mut(ai.as_mut(), u_eval.as_ref());
@@ -1076,7 +2307,13 @@ pub(crate) fn public_key_encrypt_rgsw<
 
         // a = p0*u + e0 + \beta*m
         // use u_eval as scratch
-        mod_op.elwise_scalar_mul(u_eval.as_mut(), m.as_ref(), beta_i);
+        u_eval.as_mut().fill(Mmut::MatElement::zero());
+        mod_op.elwise_fma_shoup_mut(
+            u_eval.as_mut(),
+            m.as_ref(),
+            beta_row.as_ref(),
+            beta_shoup_row.as_ref(),
+        );
         mod_op.elwise_add_mut(ai.as_mut(), u_eval.as_ref());
     });
 
@@ -1085,14 +2322,12 @@ pub(crate) fn public_key_encrypt_rgsw<
     izip!(
         rlwe_dash_m_parta.iter_mut(),
         rlwe_dash_m_partb.iter_mut(),
-        gadget_vector.iter()
+        gadget_mat.iter_rows(),
+        gadget_shoup_mat.iter_rows()
     )
-    .for_each(|(ai, bi, beta_i)| {
+    .for_each(|(ai, bi, beta_row, beta_shoup_row)| {
         // sample ephemeral secret u_i
-        let mut u = vec![0i32; ring_size];
-        fill_random_ternary_secret_with_hamming_weight(u.as_mut(), ring_size >> 1, rng);
-        let mut u_eval = Mmut::R::try_convert_from(u.as_ref(), &q);
-        ntt_op.forward(u_eval.as_mut());
+        let mut u_eval = sample_u_eval(rng);
 
         let mut u_eval_copy = Mmut::R::zeros(ring_size);
         u_eval_copy.as_mut().copy_from_slice(u_eval.as_ref());
@@ -1105,8 +2340,8 @@ pub(crate) fn public_key_encrypt_rgsw<
         ntt_op.backward(u_eval_copy.as_mut());
 
         // sample error
-        RandomGaussianDist::random_fill(rng, &q, ai.as_mut());
-        RandomGaussianDist::random_fill(rng, &q, bi.as_mut());
+        error_distribution.sample(ai.as_mut(), &q, mod_op, rng);
+        error_distribution.sample(bi.as_mut(), &q, mod_op, rng);
 
         // a = p0*u+e0
         mod_op.elwise_add_mut(ai.as_mut(), u_eval.as_ref());
@@ -1115,7 +2350,13 @@ pub(crate) fn public_key_encrypt_rgsw<
 
         // b = p1*u + e0 + \beta*m
         // use u_eval as scratch
-        mod_op.elwise_scalar_mul(u_eval.as_mut(), m.as_ref(), beta_i);
+        u_eval.as_mut().fill(Mmut::MatElement::zero());
+        mod_op.elwise_fma_shoup_mut(
+            u_eval.as_mut(),
+            m.as_ref(),
+            beta_row.as_ref(),
+            beta_shoup_row.as_ref(),
+        );
         mod_op.elwise_add_mut(bi.as_mut(), u_eval.as_ref());
     });
 }
@@ -1134,8 +2375,10 @@ pub(crate) fn public_key_encrypt_rgsw<
 /// - neg_from_s: Negative of secret polynomial to key switch from
 /// - to_s: secret polynomial to key switch to.
 pub(crate) fn rlwe_ksk_gen<
-    Mmut: MatrixMut + MatrixEntity,
-    ModOp: ArithmeticOps<Element = Mmut::MatElement> + VectorOps<Element = Mmut::MatElement>,
+    Mmut: MatrixMut + MatrixEntity + ToShoup<Modulus = Mmut::MatElement>,
+    ModOp: ArithmeticOps<Element = Mmut::MatElement>
+        + VectorOps<Element = Mmut::MatElement>
+        + ShoupVectorOps<Element = Mmut::MatElement>,
     NttOp: Ntt<Element = Mmut::MatElement>,
     R: RandomGaussianDist<[Mmut::MatElement], Parameters = Mmut::MatElement>,
     PR: RandomUniformDist<[Mmut::MatElement], Parameters = Mmut::MatElement>,
@@ -1150,6 +2393,7 @@ pub(crate) fn rlwe_ksk_gen<
     rng: &mut R,
 ) where
     <Mmut as Matrix>::R: RowMut,
+    Mmut::MatElement: Copy + Zero,
 {
     let ring_size = neg_from_s.as_ref().len();
     let d = gadget_vector.len();
@@ -1159,6 +2403,12 @@ pub(crate) fn rlwe_ksk_gen<
 
     ntt_op.forward(to_s.as_mut());
 
+    // to_s is the fixed multiplier of every `si * ai` product below.
+    let mut to_s_mat = Mmut::zeros(1, ring_size);
+    to_s_mat.get_row_mut(0).copy_from_slice(to_s.as_ref());
+    let to_s_shoup_mat = Mmut::to_shoup(&to_s_mat, q);
+    let to_s_shoup = to_s_shoup_mat.get_row_slice(0);
+
     // RLWE'_{to_s}(-from_s)
     let mut part_a = {
         let mut a = Mmut::zeros(d, ring_size);
@@ -1166,6 +2416,7 @@ pub(crate) fn rlwe_ksk_gen<
             .for_each(|ai| RandomUniformDist::random_fill(p_rng, &q, ai.as_mut()));
         a
     };
+    let mut product_space = Mmut::R::zeros(ring_size);
     izip!(
         part_a.iter_rows_mut(),
         ksk_out.iter_rows_mut(),
@@ -1174,12 +2425,18 @@ pub(crate) fn rlwe_ksk_gen<
     .for_each(|(ai, bi, beta_i)| {
         // si * ai
         ntt_op.forward(ai.as_mut());
-        mod_op.elwise_mul_mut(ai.as_mut(), to_s.as_ref());
-        ntt_op.backward(ai.as_mut());
+        product_space.as_mut().fill(Mmut::MatElement::zero());
+        mod_op.elwise_fma_shoup_mut(
+            product_space.as_mut(),
+            ai.as_ref(),
+            to_s.as_ref(),
+            to_s_shoup,
+        );
+        ntt_op.backward(product_space.as_mut());
 
         // ei + to_s*ai
         RandomGaussianDist::random_fill(rng, &q, bi.as_mut());
-        mod_op.elwise_add_mut(bi.as_mut(), ai.as_ref());
+        mod_op.elwise_add_mut(bi.as_mut(), product_space.as_ref());
 
         // beta_i * -from_s
         // use ai as scratch space
@@ -1191,8 +2448,10 @@ pub(crate) fn rlwe_ksk_gen<
 }
 
 pub(crate) fn galois_key_gen<
-    Mmut: MatrixMut + MatrixEntity,
-    ModOp: ArithmeticOps<Element = Mmut::MatElement> + VectorOps<Element = Mmut::MatElement>,
+    Mmut: MatrixMut + MatrixEntity + ToShoup<Modulus = Mmut::MatElement>,
+    ModOp: ArithmeticOps<Element = Mmut::MatElement>
+        + VectorOps<Element = Mmut::MatElement>
+        + ShoupVectorOps<Element = Mmut::MatElement>,
     NttOp: Ntt<Element = Mmut::MatElement>,
     S,
     R: RandomGaussianDist<[Mmut::MatElement], Parameters = Mmut::MatElement>,
@@ -1209,7 +2468,7 @@ pub(crate) fn galois_key_gen<
 ) where
     <Mmut as Matrix>::R: RowMut,
     Mmut::R: TryConvertFrom<[S], Parameters = Mmut::MatElement> + RowEntity,
-    Mmut::MatElement: Copy + Sub<Output = Mmut::MatElement>,
+    Mmut::MatElement: Copy + Zero + Sub<Output = Mmut::MatElement>,
 {
     let ring_size = s.len();
     let (auto_map_index, auto_map_sign) = generate_auto_map(ring_size, auto_k);
@@ -1254,7 +2513,8 @@ pub(crate) fn secret_key_encrypt_rlwe<
     ModOp: VectorOps<Element = Ro::Element>,
     NttOp: Ntt<Element = Ro::Element>,
     S,
-    R: RandomGaussianDist<[Ro::Element], Parameters = Ro::Element>,
+    R: RandomGaussianDist<[Ro::Element], Parameters = Ro::Element>
+        + RandomUniformDist<[Ro::Element], Parameters = Ro::Element>,
     PR: RandomUniformDist<[Ro::Element], Parameters = Ro::Element>,
 >(
     m: &[Ro::Element],
@@ -1262,10 +2522,12 @@ pub(crate) fn secret_key_encrypt_rlwe<
     s: &[S],
     mod_op: &ModOp,
     ntt_op: &NttOp,
+    error_distribution: &ErrorDistribution,
     p_rng: &mut PR,
     rng: &mut R,
 ) where
     Ro: TryConvertFrom<[S], Parameters = Ro::Element> + Debug,
+    Ro::Element: FromPrimitive,
 {
     let ring_size = s.len();
     assert!(m.as_ref().len() == ring_size);
@@ -1288,7 +2550,7 @@ pub(crate) fn secret_key_encrypt_rlwe<
     ntt_op.backward(sa.as_mut());
 
     // sample e
-    RandomGaussianDist::random_fill(rng, &q, b_rlwe_out.as_mut());
+    error_distribution.sample(b_rlwe_out.as_mut(), &q, mod_op, rng);
     mod_op.elwise_add_mut(b_rlwe_out.as_mut(), m.as_ref());
     mod_op.elwise_add_mut(b_rlwe_out.as_mut(), sa.as_ref());
 }
@@ -1361,16 +2623,19 @@ pub(crate) fn gen_rlwe_public_key<
     ModOp: VectorOps<Element = Ro::Element>,
     NttOp: Ntt<Element = Ro::Element>,
     PRng: RandomUniformDist<[Ro::Element], Parameters = Ro::Element>,
-    Rng: RandomGaussianDist<[Ro::Element], Parameters = Ro::Element>,
+    Rng: RandomGaussianDist<[Ro::Element], Parameters = Ro::Element>
+        + RandomUniformDist<[Ro::Element], Parameters = Ro::Element>,
 >(
     part_b_out: &mut Ro,
     s: &[S],
     ntt_op: &NttOp,
     mod_op: &ModOp,
+    error_distribution: &ErrorDistribution,
     p_rng: &mut PRng,
     rng: &mut Rng,
 ) where
     Ro: TryConvertFrom<[S], Parameters = Ro::Element>,
+    Ro::Element: FromPrimitive,
 {
     let ring_size = s.len();
     assert!(part_b_out.as_ref().len() == ring_size);
@@ -1392,7 +2657,7 @@ pub(crate) fn gen_rlwe_public_key<
     ntt_op.backward(sa.as_mut());
 
     // s*a + e
-    RandomGaussianDist::random_fill(rng, &q, part_b_out.as_mut());
+    error_distribution.sample(part_b_out.as_mut(), &q, mod_op, rng);
     mod_op.elwise_add_mut(part_b_out.as_mut(), sa.as_ref());
 }
 
@@ -1435,7 +2700,13 @@ pub(crate) fn decrypt_rlwe<
 }
 
 // Measures noise in degree 1 RLWE ciphertext against encoded ideal message
-// encoded_m
+// encoded_m. Operates on a single word-sized modulus; making this
+// RNS-aware (reconstructing the centered residue via
+// `RnsModulus::signed_balanced_lift` before taking the max coefficient, for
+// a ciphertext whose `ModOp`/`NttOp` dispatch component-wise across an RNS
+// basis) needs `rlwe_by_rgsw`/`rgsw_by_rgsw_inplace`/`galois_auto` to run
+// over such a basis in the first place, which is backend/NTT-table
+// plumbing living outside this file (see `RnsModulus::gadget_vector_per_prime`).
 pub(crate) fn measure_noise<
     Mmut: MatrixMut + Matrix,
     ModOp: VectorOps<Element = Mmut::MatElement>,
@@ -1501,7 +2772,7 @@ pub(crate) mod tests {
     use rand::{thread_rng, Rng};
 
     use crate::{
-        backend::{ModInit, ModularOpsU64, VectorOps},
+        backend::{ModInit, ModularOpsU64, ShoupVectorOps, VectorOps},
         decomposer::{gadget_vector, DefaultDecomposer},
         ntt::{self, Ntt, NttBackendU64, NttInit},
         random::{DefaultSecureRng, NewWithSeed, RandomUniformDist},
@@ -1510,15 +2781,19 @@ pub(crate) mod tests {
             RgswCiphertext, RgswCiphertextEvaluationDomain, RlweCiphertext, RlwePublicKey,
             SeededAutoKey, SeededRgswCiphertext, SeededRlweCiphertext, SeededRlwePublicKey,
         },
-        utils::{generate_prime, negacyclic_mul, TryConvertFrom},
+        utils::{generate_prime, negacyclic_mul, ToShoup, TryConvertFrom},
         Matrix, Secret,
     };
 
     use super::{
-        decrypt_rlwe, galois_auto, galois_key_gen, generate_auto_map, public_key_encrypt_rlwe,
+        auto_map_ks_for_expand, decrypt_rlwe, expand, galois_auto, galois_key_gen,
+        generate_auto_map, generate_prime_chain, pack, public_key_encrypt_rlwe,
         rgsw_by_rgsw_inplace, rlwe_by_rgsw, secret_key_encrypt_rgsw, secret_key_encrypt_rlwe,
-        RlweSecret,
+        ErrorDistribution, RlweSecret, RnsModulus,
     };
+    use super::DispatchedNttBackendU64;
+    #[cfg(feature = "simd")]
+    use super::{SimdModularOpsU64, SimdNttBackendU64};
 
     #[test]
     fn rlwe_encrypt_decryption() {
@@ -1555,6 +2830,7 @@ pub(crate) mod tests {
             s.values(),
             &mod_op,
             &ntt_op,
+            &ErrorDistribution::Gaussian,
             &mut p_rng,
             &mut rng,
         );
@@ -1624,6 +2900,7 @@ pub(crate) mod tests {
                     s.values(),
                     &ntt_op,
                     &mod_op,
+                    &ErrorDistribution::Gaussian,
                     &mut pk_prng,
                     &mut rng,
                 );
@@ -1656,6 +2933,7 @@ pub(crate) mod tests {
                 s.values(),
                 &mod_op,
                 &ntt_op,
+                &ErrorDistribution::Gaussian,
                 &mut p_rng,
                 &mut rng,
             );
@@ -1669,6 +2947,7 @@ pub(crate) mod tests {
         rlwe_by_rgsw(
             &mut rlwe_in_ct,
             &rgsw_ct.data,
+            rgsw_ct.shoup_data(),
             &mut scratch_space,
             &decomposer,
             &ntt_op,
@@ -1711,6 +2990,128 @@ pub(crate) mod tests {
         );
     }
 
+    #[test]
+    fn pk_encrypt_rgsw_works_with_uniform_secret_distribution() {
+        // Same shape as `rlwe_by_rgsw_works`'s public-key branch, but with the
+        // ephemeral secret `u` drawn from `SecretDistribution::Uniform`
+        // instead of ternary -- regression test for `public_key_encrypt_rgsw`
+        // actually honoring `secret_distribution` for every variant.
+        let logq = 50;
+        let logp = 2;
+        let ring_size = 1 << 9;
+        let q = generate_prime(logq, ring_size, 1u64 << logq).unwrap();
+        let p = 1u64 << logp;
+        let d_rgsw = 10;
+        let logb = 5;
+
+        let mut rng = DefaultSecureRng::new_seeded([0u8; 32]);
+
+        let s = RlweSecret::random((ring_size >> 1) as usize, ring_size as usize);
+
+        let mut m0 = vec![0u64; ring_size as usize];
+        RandomUniformDist::<[u64]>::random_fill(&mut rng, &(1u64 << logp), m0.as_mut_slice());
+        let mut m1 = vec![0u64; ring_size as usize];
+        m1[thread_rng().gen_range(0..ring_size) as usize] = 1;
+
+        let ntt_op = NttBackendU64::new(q, ring_size as usize);
+        let mod_op = ModularOpsU64::new(q);
+        let gadget_vector = gadget_vector(logq, logb, d_rgsw);
+
+        // first create public key
+        let mut pk_seed = [0u8; 32];
+        rng.fill_bytes(&mut pk_seed);
+        let mut pk_prng = DefaultSecureRng::new_seeded(pk_seed);
+        let mut seeded_pk = SeededRlwePublicKey::<Vec<u64>, _>::empty(ring_size as usize, pk_seed, q);
+        gen_rlwe_public_key(
+            &mut seeded_pk.data,
+            s.values(),
+            &ntt_op,
+            &mod_op,
+            &ErrorDistribution::Gaussian,
+            &mut pk_prng,
+            &mut rng,
+        );
+        let pk = RlwePublicKey::<Vec<Vec<u64>>, DefaultSecureRng>::from(&seeded_pk);
+
+        // Encrypt m1 as RGSW(m1) with a uniform ephemeral secret
+        let rgsw_ct = _pk_encrypt_rgsw_with_distribution(
+            &m1,
+            &pk,
+            &gadget_vector,
+            &mod_op,
+            &ntt_op,
+            &SecretDistribution::Uniform,
+        );
+        let rgsw_ct = RgswCiphertextEvaluationDomain::<_, DefaultSecureRng, NttBackendU64>::from(
+            &RgswCiphertext {
+                data: rgsw_ct.data,
+                modulus: q,
+            },
+        );
+
+        // Encrypt m0 as RLWE(m0)
+        let mut rlwe_in_ct = {
+            let mut rlwe_seed = [0u8; 32];
+            rng.fill_bytes(&mut rlwe_seed);
+            let mut seeded_rlwe_in_ct =
+                SeededRlweCiphertext::<_, [u8; 32]>::empty(ring_size as usize, rlwe_seed, q);
+            let mut p_rng = DefaultSecureRng::new_seeded(rlwe_seed);
+            let encoded_m = m0
+                .iter()
+                .map(|v| (((*v as f64) * q as f64) / (p as f64)).round() as u64)
+                .collect_vec();
+            secret_key_encrypt_rlwe(
+                &encoded_m,
+                &mut seeded_rlwe_in_ct.data,
+                s.values(),
+                &mod_op,
+                &ntt_op,
+                &ErrorDistribution::Gaussian,
+                &mut p_rng,
+                &mut rng,
+            );
+
+            RlweCiphertext::<Vec<Vec<u64>>, DefaultSecureRng>::from(&seeded_rlwe_in_ct)
+        };
+
+        // RLWE(m0m1) = RLWE(m0) x RGSW(m1)
+        let mut scratch_space = vec![vec![0u64; ring_size as usize]; d_rgsw + 2];
+        let decomposer = DefaultDecomposer::new(q, logb, d_rgsw);
+        rlwe_by_rgsw(
+            &mut rlwe_in_ct,
+            &rgsw_ct.data,
+            rgsw_ct.shoup_data(),
+            &mut scratch_space,
+            &decomposer,
+            &ntt_op,
+            &mod_op,
+        );
+
+        // Decrypt RLWE(m0m1)
+        let mut encoded_m0m1_back = vec![0u64; ring_size as usize];
+        decrypt_rlwe(
+            &rlwe_in_ct,
+            s.values(),
+            &mut encoded_m0m1_back,
+            &ntt_op,
+            &mod_op,
+        );
+        let m0m1_back = encoded_m0m1_back
+            .iter()
+            .map(|v| (((*v as f64 * p as f64) / (q as f64)).round() as u64) % p)
+            .collect_vec();
+
+        let mul_mod = |v0: &u64, v1: &u64| (v0 * v1) % p;
+        let m0m1 = negacyclic_mul(&m0, &m1, mul_mod, p);
+
+        assert!(
+            m0m1 == m0m1_back,
+            "Expected {:?} \n Got {:?}",
+            m0m1,
+            m0m1_back
+        );
+    }
+
     fn _secret_encrypt_rlwe(
         m: &[u64],
         s: &[i32],
@@ -1733,6 +3134,7 @@ pub(crate) mod tests {
             s,
             mod_op,
             ntt_op,
+            &ErrorDistribution::Gaussian,
             &mut p_rng,
             &mut rng,
         );
@@ -1772,6 +3174,7 @@ pub(crate) mod tests {
             rlwe_by_rgsw(
                 &mut rlwe,
                 &rgsw_ct.data,
+                rgsw_ct.shoup_data(),
                 &mut scratch_matrix_dplus2_ring,
                 &decomposer,
                 &ntt_op,
@@ -1792,6 +3195,29 @@ pub(crate) mod tests {
         gadget_vector: &[u64],
         mod_op: &ModularOpsU64,
         ntt_op: &NttBackendU64,
+    ) -> RgswCiphertext<Vec<Vec<u64>>> {
+        let (_, ring_size) = Matrix::dimension(&public_key.data);
+        _pk_encrypt_rgsw_with_distribution(
+            m,
+            public_key,
+            gadget_vector,
+            mod_op,
+            ntt_op,
+            &SecretDistribution::Ternary {
+                hamming_weight: ring_size >> 1,
+            },
+        )
+    }
+
+    // Same as `_pk_encrypt_rgsw`, but lets callers pick the ephemeral secret's
+    // distribution instead of hard-coding ternary.
+    fn _pk_encrypt_rgsw_with_distribution(
+        m: &[u64],
+        public_key: &RlwePublicKey<Vec<Vec<u64>>, DefaultSecureRng>,
+        gadget_vector: &[u64],
+        mod_op: &ModularOpsU64,
+        ntt_op: &NttBackendU64,
+        secret_distribution: &SecretDistribution,
     ) -> RgswCiphertext<Vec<Vec<u64>>> {
         let (_, ring_size) = Matrix::dimension(&public_key.data);
         let d_rgsw = gadget_vector.len();
@@ -1809,6 +3235,8 @@ pub(crate) mod tests {
             gadget_vector,
             mod_op,
             ntt_op,
+            secret_distribution,
+            &ErrorDistribution::Gaussian,
             &mut rng,
         );
 
@@ -1842,7 +3270,6 @@ pub(crate) mod tests {
             rgsw_seed,
             q,
         );
-        let mut p_rng = DefaultSecureRng::new_seeded(rgsw_seed);
         secret_key_encrypt_rgsw(
             &mut seeded_rgsw_ct.data,
             m,
@@ -1850,7 +3277,8 @@ pub(crate) mod tests {
             s,
             mod_op,
             ntt_op,
-            &mut p_rng,
+            &ErrorDistribution::Gaussian,
+            rgsw_seed,
             &mut rng,
         );
 
@@ -1880,22 +3308,38 @@ pub(crate) mod tests {
         let mut neg_s = s_poly.clone();
         mod_op.elwise_neg_mut(neg_s.as_mut());
         let neg_sm0m1 = negacyclic_mul(&neg_s, &m, mul_mod, q);
+
+        // Same Shoup-cached gadget-vector scaling as `secret_key_encrypt_rgsw`
+        // / `public_key_encrypt_rgsw`, so this noise-printing helper scales
+        // by `gadget_vector[j]` without falling back to the general `%`
+        // operator either.
+        let mut gadget_mat = vec![vec![0u64; ring_size]; d_rgsw];
+        izip!(gadget_mat.iter_mut(), gadget_vector.iter())
+            .for_each(|(row, beta_i)| row.fill(*beta_i));
+        let gadget_shoup_mat = Vec::<Vec<u64>>::to_shoup(&gadget_mat, q);
+
         for i in 0..2 {
             for j in 0..d_rgsw {
                 let ideal_m = {
                     if i == 0 {
                         // RLWE(\beta^j -s * m)
                         let mut beta_neg_sm0m1 = vec![0u64; ring_size as usize];
-                        mod_op.elwise_scalar_mul(
+                        mod_op.elwise_fma_shoup_mut(
                             beta_neg_sm0m1.as_mut(),
                             &neg_sm0m1,
-                            &gadget_vector[j],
+                            &gadget_mat[j],
+                            &gadget_shoup_mat[j],
                         );
                         beta_neg_sm0m1
                     } else {
                         // RLWE(\beta^j  m)
                         let mut beta_m0m1 = vec![0u64; ring_size as usize];
-                        mod_op.elwise_scalar_mul(beta_m0m1.as_mut(), &m, &gadget_vector[j]);
+                        mod_op.elwise_fma_shoup_mut(
+                            beta_m0m1.as_mut(),
+                            &m,
+                            &gadget_mat[j],
+                            &gadget_shoup_mat[j],
+                        );
                         beta_m0m1
                     }
                 };
@@ -1947,6 +3391,7 @@ pub(crate) mod tests {
                 s.values(),
                 &ntt_op,
                 &mod_op,
+                &ErrorDistribution::Gaussian,
                 &mut pk_prng,
                 &mut rng,
             );
@@ -2125,6 +3570,7 @@ pub(crate) mod tests {
             s.values(),
             &mod_op,
             &ntt_op,
+            &ErrorDistribution::Gaussian,
             &mut p_rng,
             &mut rng,
         );
@@ -2160,6 +3606,7 @@ pub(crate) mod tests {
         galois_auto(
             &mut rlwe_m,
             &auto_key.data,
+            &auto_key.shoup_data,
             &mut scratch_space,
             &auto_map_index,
             &auto_map_sign,
@@ -2208,4 +3655,358 @@ pub(crate) mod tests {
 
         assert_eq!(m_k_back, m_k);
     }
+
+    #[test]
+    fn expand_pack_round_trip_works() {
+        let logq = 50;
+        let ring_size = 1 << 4;
+        let q = generate_prime(logq, 2 * ring_size, 1u64 << logq).unwrap();
+        let logp = 3;
+        let p = 1u64 << logp;
+        let d_rgsw = 10;
+        let logb = 5;
+
+        let mut rng = DefaultSecureRng::new();
+        let s = RlweSecret::random((ring_size >> 1) as usize, ring_size as usize);
+
+        let mut m = vec![0u64; ring_size as usize];
+        RandomUniformDist::random_fill(&mut rng, &p, m.as_mut_slice());
+        let encoded_m = m
+            .iter()
+            .map(|v| (((*v as f64 * q as f64) / (p as f64)).round() as u64))
+            .collect_vec();
+
+        let ntt_op = NttBackendU64::new(q, ring_size as usize);
+        let mod_op = ModularOpsU64::new(q);
+
+        // RLWE_{s}(m)
+        let mut seed_rlwe = [0u8; 32];
+        rng.fill_bytes(&mut seed_rlwe);
+        let mut seeded_rlwe_m = SeededRlweCiphertext::empty(ring_size as usize, seed_rlwe, q);
+        let mut p_rng = DefaultSecureRng::new_seeded(seed_rlwe);
+        secret_key_encrypt_rlwe(
+            &encoded_m,
+            &mut seeded_rlwe_m.data,
+            s.values(),
+            &mod_op,
+            &ntt_op,
+            &ErrorDistribution::Gaussian,
+            &mut p_rng,
+            &mut rng,
+        );
+        let rlwe_m = RlweCiphertext::<Vec<Vec<u64>>, DefaultSecureRng>::from(&seeded_rlwe_m);
+
+        // Generate the galois keys `expand` needs, one per round.
+        let gadget_vector = gadget_vector(logq, logb, d_rgsw);
+        let ks = auto_map_ks_for_expand(ring_size as usize);
+        let mut auto_keys = vec![];
+        let mut auto_keys_shoup = vec![];
+        for k in ks.iter() {
+            let mut seed_auto = [0u8; 32];
+            rng.fill_bytes(&mut seed_auto);
+            let mut seeded_auto_key =
+                SeededAutoKey::empty(ring_size as usize, d_rgsw, seed_auto, q);
+            let mut p_rng = DefaultSecureRng::new_seeded(seed_auto);
+            galois_key_gen(
+                &mut seeded_auto_key.data,
+                s.values(),
+                *k,
+                &gadget_vector,
+                &mod_op,
+                &ntt_op,
+                &mut p_rng,
+                &mut rng,
+            );
+            let auto_key =
+                AutoKeyEvaluationDomain::<Vec<Vec<u64>>, DefaultSecureRng, NttBackendU64>::from(
+                    &seeded_auto_key,
+                );
+            auto_keys.push(auto_key.data);
+            auto_keys_shoup.push(auto_key.shoup_data);
+        }
+
+        let decomposer = DefaultDecomposer::new(q, logb, d_rgsw);
+        let mut scratch_space = vec![vec![0u64; ring_size as usize]; d_rgsw + 2];
+        let expanded = expand(
+            &rlwe_m.data,
+            &auto_keys,
+            &auto_keys_shoup,
+            &mut scratch_space,
+            &mod_op,
+            &ntt_op,
+            &decomposer,
+        );
+        assert_eq!(expanded.len(), ring_size as usize);
+
+        let packed = pack(&expanded, ring_size as usize, &mod_op);
+        let packed = RlweCiphertext::<Vec<Vec<u64>>, DefaultSecureRng>::from_raw(packed, false);
+
+        let mut encoded_m_back = vec![0u64; ring_size as usize];
+        decrypt_rlwe(&packed, s.values(), &mut encoded_m_back, &ntt_op, &mod_op);
+        let m_back = encoded_m_back
+            .iter()
+            .map(|v| (((*v as f64) * p as f64) / (q as f64)).round() as u64 % p)
+            .collect_vec();
+
+        assert_eq!(m, m_back);
+    }
+
+    #[test]
+    fn shoup_gadget_vector_scalar_mul_matches_plain() {
+        let logq = 50;
+        let ring_size = 1 << 4;
+        let q = generate_prime(logq, 2 * ring_size, 1u64 << logq).unwrap();
+        let d_rgsw = 10;
+        let logb = 5;
+        let gadget_vector = gadget_vector(logq, logb, d_rgsw);
+
+        let mod_op = ModularOpsU64::new(q);
+        let mut rng = DefaultSecureRng::new();
+        let mut m = vec![0u64; ring_size as usize];
+        RandomUniformDist::random_fill(&mut rng, &q, m.as_mut_slice());
+
+        for beta_i in gadget_vector.iter() {
+            let mut plain = vec![0u64; ring_size as usize];
+            mod_op.elwise_scalar_mul(plain.as_mut_slice(), m.as_slice(), beta_i);
+
+            let beta_row = vec![vec![*beta_i; ring_size as usize]];
+            let beta_row_shoup = Vec::<Vec<u64>>::to_shoup(&beta_row, q);
+
+            let mut via_shoup = vec![0u64; ring_size as usize];
+            mod_op.elwise_fma_shoup_mut(
+                via_shoup.as_mut_slice(),
+                m.as_slice(),
+                &beta_row[0],
+                &beta_row_shoup[0],
+            );
+
+            assert_eq!(plain, via_shoup);
+        }
+    }
+
+    #[test]
+    fn rns_modulus_reconstructs_residues() {
+        let ring_size = 1 << 4;
+        let primes = vec![
+            generate_prime(28, 2 * ring_size, 1u64 << 28).unwrap(),
+            generate_prime(30, 2 * ring_size, 1u64 << 30).unwrap(),
+            generate_prime(32, 2 * ring_size, 1u64 << 32).unwrap(),
+        ];
+        let q: u128 = primes.iter().map(|&p| p as u128).product();
+        let rns = RnsModulus::new(primes.clone());
+
+        let mut rng = thread_rng();
+        for _ in 0..20 {
+            let x: u128 = rng.gen_range(0..q);
+            let residues: Vec<u64> = primes.iter().map(|&p| (x % p as u128) as u64).collect();
+            assert_eq!(rns.reconstruct(&residues), x);
+        }
+    }
+
+    #[test]
+    fn rns_modulus_gadget_vector_per_prime_has_one_chain_per_limb() {
+        let ring_size = 1 << 4;
+        let primes = vec![
+            generate_prime(28, 2 * ring_size, 1u64 << 28).unwrap(),
+            generate_prime(30, 2 * ring_size, 1u64 << 30).unwrap(),
+        ];
+        let rns = RnsModulus::new(primes.clone());
+        let log_b = 5;
+
+        let gadget = rns.gadget_vector_per_prime(log_b);
+        assert_eq!(gadget.len(), primes.len());
+        izip!(primes.iter(), gadget.iter()).for_each(|(&p, chain)| {
+            let bit_len = 64 - p.leading_zeros() as usize;
+            let expected_d = (bit_len + log_b - 1) / log_b;
+            assert_eq!(chain.len(), expected_d);
+            assert_eq!(chain[0], 1);
+        });
+    }
+
+    #[test]
+    fn rns_modulus_signed_balanced_lift_centers_around_zero() {
+        let ring_size = 1 << 4;
+        let primes = vec![
+            generate_prime(28, 2 * ring_size, 1u64 << 28).unwrap(),
+            generate_prime(30, 2 * ring_size, 1u64 << 30).unwrap(),
+        ];
+        let q: u128 = primes.iter().map(|&p| p as u128).product();
+        let rns = RnsModulus::new(primes.clone());
+
+        // x just below q/2 lifts to itself (still positive).
+        let x_pos = q / 2 - 1;
+        let residues: Vec<u64> = primes.iter().map(|&p| (x_pos % p as u128) as u64).collect();
+        assert_eq!(rns.signed_balanced_lift(&residues), x_pos as i128);
+
+        // x just above q/2 lifts to a negative representative of the same
+        // residue class.
+        let x_neg = q / 2 + 1;
+        let residues: Vec<u64> = primes.iter().map(|&p| (x_neg % p as u128) as u64).collect();
+        assert_eq!(
+            rns.signed_balanced_lift(&residues),
+            x_neg as i128 - q as i128
+        );
+    }
+
+    #[test]
+    fn rns_modulus_drop_last_prime_preserves_value_mod_smaller_basis() {
+        let ring_size = 1 << 4;
+        let primes = vec![
+            generate_prime(28, 2 * ring_size, 1u64 << 28).unwrap(),
+            generate_prime(30, 2 * ring_size, 1u64 << 30).unwrap(),
+            generate_prime(32, 2 * ring_size, 1u64 << 32).unwrap(),
+        ];
+        let rns = RnsModulus::new(primes.clone());
+        let smaller_q: u128 = primes[..2].iter().map(|&p| p as u128).product();
+
+        let mut rng = thread_rng();
+        let q: u128 = primes.iter().map(|&p| p as u128).product();
+        let x: u128 = rng.gen_range(0..q);
+        let residues: Vec<u64> = primes.iter().map(|&p| (x % p as u128) as u64).collect();
+
+        let (smaller, smaller_residues) = rns.drop_last_prime(&residues);
+        assert_eq!(smaller.primes(), &primes[..2]);
+        assert_eq!(smaller.reconstruct(&smaller_residues), x % smaller_q);
+    }
+
+    #[test]
+    fn generate_prime_chain_is_descending_and_distinct() {
+        let ring_size = 1 << 4;
+        let limbs = 4;
+        let primes = generate_prime_chain(28, ring_size, limbs);
+
+        assert_eq!(primes.len(), limbs);
+        primes.windows(2).for_each(|w| assert!(w[0] > w[1]));
+        primes
+            .iter()
+            .for_each(|&p| assert_eq!(p % (2 * ring_size), 1));
+    }
+
+    #[test]
+    fn rns_modulus_for_ring_reconstructs() {
+        let ring_size = 1 << 4;
+        let rns = RnsModulus::for_ring(28, ring_size, 3);
+
+        let q: u128 = rns.primes().iter().map(|&p| p as u128).product();
+        let mut rng = thread_rng();
+        let x: u128 = rng.gen_range(0..q);
+        let residues: Vec<u64> = rns
+            .primes()
+            .iter()
+            .map(|&p| (x % p as u128) as u64)
+            .collect();
+
+        assert_eq!(rns.reconstruct(&residues), x);
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_modular_ops_matches_scalar_modular_ops() {
+        let ring_size = 1 << 5;
+        let q = generate_prime(55, ring_size, 1u64 << 55).unwrap();
+
+        let scalar_op = ModularOpsU64::new(q);
+        let simd_op = SimdModularOpsU64::new(q);
+
+        let mut rng = thread_rng();
+        // odd length, so every op also exercises simd_for_each's scalar tail.
+        let len = (ring_size - 1) as usize;
+        let a: Vec<u64> = (0..len).map(|_| rng.gen_range(0..q)).collect();
+        let b: Vec<u64> = (0..len).map(|_| rng.gen_range(0..q)).collect();
+        let scalar = rng.gen_range(0..q);
+
+        let mut expect_add = a.clone();
+        scalar_op.elwise_add_mut(&mut expect_add, &b);
+        let mut actual_add = a.clone();
+        simd_op.elwise_add_mut(&mut actual_add, &b);
+        assert_eq!(expect_add, actual_add);
+
+        let mut expect_sub = a.clone();
+        scalar_op.elwise_sub_mut(&mut expect_sub, &b);
+        let mut actual_sub = a.clone();
+        simd_op.elwise_sub_mut(&mut actual_sub, &b);
+        assert_eq!(expect_sub, actual_sub);
+
+        let mut expect_neg = a.clone();
+        scalar_op.elwise_neg_mut(&mut expect_neg);
+        let mut actual_neg = a.clone();
+        simd_op.elwise_neg_mut(&mut actual_neg);
+        assert_eq!(expect_neg, actual_neg);
+
+        let mut expect_mul = a.clone();
+        scalar_op.elwise_mul_mut(&mut expect_mul, &b);
+        let mut actual_mul = a.clone();
+        simd_op.elwise_mul_mut(&mut actual_mul, &b);
+        assert_eq!(expect_mul, actual_mul);
+
+        let mut expect_scalar_mul = vec![0u64; len];
+        scalar_op.elwise_scalar_mul(&mut expect_scalar_mul, &a, &scalar);
+        let mut actual_scalar_mul = vec![0u64; len];
+        simd_op.elwise_scalar_mul(&mut actual_scalar_mul, &a, &scalar);
+        assert_eq!(expect_scalar_mul, actual_scalar_mul);
+
+        let mut expect_fma = vec![1u64; len];
+        scalar_op.elwise_fma_mut(&mut expect_fma, &a, &b);
+        let mut actual_fma = vec![1u64; len];
+        simd_op.elwise_fma_mut(&mut actual_fma, &a, &b);
+        assert_eq!(expect_fma, actual_fma);
+
+        let b_shoup_mat = Vec::<Vec<u64>>::to_shoup(&vec![b.clone()], q);
+        let b_shoup = &b_shoup_mat[0];
+        let mut expect_fma_shoup = vec![2u64; len];
+        scalar_op.elwise_fma_shoup_mut(&mut expect_fma_shoup, &a, &b, b_shoup);
+        let mut actual_fma_shoup = vec![2u64; len];
+        simd_op.elwise_fma_shoup_mut(&mut actual_fma_shoup, &a, &b, b_shoup);
+        assert_eq!(expect_fma_shoup, actual_fma_shoup);
+
+        assert_eq!(scalar_op.neg(&a[0]), simd_op.neg(&a[0]));
+        assert_eq!(scalar_op.modulus(), simd_op.modulus());
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_ntt_backend_forward_backward_round_trips() {
+        let ring_size = 1 << 5;
+        let q = generate_prime(55, 2 * ring_size, 1u64 << 55).unwrap();
+        let ntt_op = SimdNttBackendU64::new(q, ring_size as usize);
+
+        let mut rng = thread_rng();
+        let original: Vec<u64> = (0..ring_size as usize).map(|_| rng.gen_range(0..q)).collect();
+
+        let mut v = original.clone();
+        ntt_op.forward(&mut v);
+        // A non-trivial polynomial's NTT is, in general, not equal to itself.
+        assert_ne!(v, original);
+        ntt_op.backward(&mut v);
+        assert_eq!(v, original);
+    }
+
+    #[test]
+    fn dispatched_ntt_backend_negacyclic_product_matches_schoolbook_reference() {
+        let mut rng = thread_rng();
+
+        // A handful of (ring_size, logq) pairs, as requested.
+        for (logq, ring_size) in [(50usize, 1u64 << 4), (55, 1 << 5), (45, 1 << 6)] {
+            let q = generate_prime(logq, 2 * ring_size, 1u64 << logq).unwrap();
+            let ring_size = ring_size as usize;
+
+            let ntt_op = DispatchedNttBackendU64::new(q, ring_size);
+            let mod_op = ModularOpsU64::new(q);
+
+            let a: Vec<u64> = (0..ring_size).map(|_| rng.gen_range(0..q)).collect();
+            let b: Vec<u64> = (0..ring_size).map(|_| rng.gen_range(0..q)).collect();
+
+            let mut a_eval = a.clone();
+            ntt_op.forward(&mut a_eval);
+            let mut b_eval = b.clone();
+            ntt_op.forward(&mut b_eval);
+            mod_op.elwise_mul_mut(&mut a_eval, &b_eval);
+            ntt_op.backward(&mut a_eval);
+
+            let mul_mod = |x: &u64, y: &u64| ((*x as u128 * *y as u128) % q as u128) as u64;
+            let expected = negacyclic_mul(&a, &b, mul_mod, q);
+
+            assert_eq!(a_eval, expected, "mismatch for ring_size={ring_size}, q={q}");
+        }
+    }
 }